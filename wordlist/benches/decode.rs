@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate criterion;
+extern crate xmr_wordlist;
+
+use criterion::{black_box, Criterion};
+
+use xmr_wordlist::{from_words_with_options, to_words, DecodeOptions, Language};
+
+fn bench_decode(c: &mut Criterion) {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(89)).collect::<Vec<_>>();
+    let phrase = to_words(&seed, Language::English).unwrap();
+    let one_wrong = phrase.replacen(phrase.split(' ').next().unwrap(), "xyzzy", 1);
+
+    let options = DecodeOptions::new();
+    c.bench_function("decode one wrong word", |b| {
+        b.iter(|| from_words_with_options(black_box(&one_wrong), Language::English, &options))
+    });
+
+    let options = DecodeOptions::new().fail_fast(true);
+    c.bench_function("decode one wrong word fail fast", |b| {
+        b.iter(|| from_words_with_options(black_box(&one_wrong), Language::English, &options))
+    });
+}
+
+criterion_group!(benches, bench_decode);
+criterion_main!(benches);
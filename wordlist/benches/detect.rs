@@ -0,0 +1,20 @@
+#[macro_use]
+extern crate criterion;
+extern crate xmr_wordlist;
+
+use criterion::{black_box, Criterion};
+
+use xmr_wordlist::{detect, to_words, Language};
+
+fn bench_detect(c: &mut Criterion) {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(89)).collect::<Vec<_>>();
+
+    for &language in &[Language::English, Language::Spanish, Language::Japanese] {
+        let phrase = to_words(&seed, language).unwrap();
+        let name = format!("detect {}", language.english_name());
+        c.bench_function(&name, |b| b.iter(|| detect(black_box(&phrase))));
+    }
+}
+
+criterion_group!(benches, bench_detect);
+criterion_main!(benches);
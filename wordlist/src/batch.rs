@@ -0,0 +1,29 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use {from_words, Error, Language};
+
+/// Converts many phrases to their seed bytes.
+///
+/// Returns the result of [`from_words`] for each phrase, in order. The
+/// lookup index of the language is built once and shared by all the
+/// phrases.
+pub fn decode_batch(phrases: &[&str], language: Language) -> Vec<Result<Vec<u8>, Error>> {
+    phrases
+        .iter()
+        .map(|phrase| from_words(phrase, language))
+        .collect()
+}
+
+/// Same as [`decode_batch`], but the phrases are decoded in parallel on the
+/// rayon thread pool.
+///
+/// The lookup index is read-only once built, and its first use is
+/// synchronized, so threads racing on it build it only once.
+#[cfg(feature = "rayon")]
+pub fn decode_batch_par(phrases: &[&str], language: Language) -> Vec<Result<Vec<u8>, Error>> {
+    phrases
+        .par_iter()
+        .map(|phrase| from_words(phrase, language))
+        .collect()
+}
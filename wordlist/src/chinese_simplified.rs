@@ -1,4 +1,4 @@
-pub const WORDLIST: &'static [&'static str] = &[
+pub const WORDLIST: &[&str] = &[
     "的",
     "一",
     "是",
@@ -1626,3 +1626,5 @@ pub const WORDLIST: &'static [&'static str] = &[
     "浙",
     "貌",
 ];
+
+const _: () = assert!(WORDLIST.len() == ::WORDLIST_LENGTH);
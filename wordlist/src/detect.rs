@@ -0,0 +1,397 @@
+use {
+    constant_time_eq, from_words, from_words_with_status, to_words, ChecksumPolicy, ChecksumStatus,
+    DecodeOptions, Error, Language, Script, MAX_WORDS,
+};
+
+/// Detects the language of a phrase.
+///
+/// A language matches when every word of the phrase is in its word list,
+/// full or trimmed to the unique prefix length. The checksum isn't
+/// verified.
+///
+/// When no language holds every word but each word is in some language,
+/// [`Error::MixedLanguages`] tells which language each word belongs to.
+#[must_use = "the detected language is returned"]
+pub fn detect(phrase: &str) -> Result<Language, Error> {
+    let mut candidates = candidates(phrase)?;
+
+    if candidates.len() == 1 {
+        Ok(candidates.remove(0))
+    } else {
+        Err(Error::AmbiguousLanguage(candidates))
+    }
+}
+
+/// Detects the language of a phrase, using the checksum to break ties.
+///
+/// Same as [`detect`], but when more than one language matches only the
+/// ones where the phrase decodes with a valid checksum are kept.
+#[must_use = "the detected language is returned"]
+pub fn detect_with_checksum(phrase: &str) -> Result<Language, Error> {
+    let candidates = candidates(phrase)?;
+    if candidates.len() == 1 {
+        return Ok(candidates[0]);
+    }
+
+    let mut valid = candidates
+        .into_iter()
+        .filter(|&language| from_words(phrase, language).is_ok())
+        .collect::<Vec<_>>();
+    debug!(?valid, "languages with a valid checksum");
+
+    match valid.len() {
+        0 => Err(Error::NoLanguageMatch),
+        1 => Ok(valid.remove(0)),
+        _ => Err(Error::AmbiguousLanguage(valid)),
+    }
+}
+
+/// The result of [`analyze`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DetectionResult {
+    /// The detected language.
+    pub language: Language,
+    /// Whether the phrase has a checksum word and it matches.
+    pub checksum_valid: bool,
+    /// The number of words of the phrase.
+    pub word_count: usize,
+}
+
+/// Detects the language of a phrase and checks its checksum at once, e.g.
+/// for the preflight of an import screen.
+///
+/// The language is detected like [`detect_with_checksum`] does, decoding
+/// the phrase once per candidate language. Unlike [`from_words_auto`] a
+/// checksum word that doesn't match isn't an error, it's reported in
+/// [`DetectionResult::checksum_valid`].
+pub fn analyze(phrase: &str) -> Result<DetectionResult, Error> {
+    // The checksum status both breaks ties and is the reported result.
+    let options = DecodeOptions::new().checksum_policy(ChecksumPolicy::Warn);
+    let mut decoded = candidates(phrase)?
+        .into_iter()
+        .map(|language| {
+            let status = from_words_with_status(phrase, language, &options)
+                .ok()
+                .map(|(_, status)| status);
+            (language, status)
+        })
+        .collect::<Vec<_>>();
+
+    if decoded.len() > 1 {
+        decoded.retain(|&(_, status)| {
+            status == Some(ChecksumStatus::Valid) || status == Some(ChecksumStatus::Missing)
+        });
+        debug!(?decoded, "languages with a valid checksum");
+
+        match decoded.len() {
+            0 => return Err(Error::NoLanguageMatch),
+            1 => {}
+            _ => {
+                let languages = decoded.into_iter().map(|(language, _)| language).collect();
+                return Err(Error::AmbiguousLanguage(languages));
+            }
+        }
+    }
+
+    let (language, status) = decoded[0];
+    Ok(DetectionResult {
+        language,
+        checksum_valid: status == Some(ChecksumStatus::Valid),
+        word_count: phrase.split_whitespace().count(),
+    })
+}
+
+/// Converts a given phrase to the seed bytes, detecting the language.
+///
+/// A first line naming the language, e.g. `"Japanese"` or `"lang: ja"`,
+/// selects the language and is stripped before decoding the words. With
+/// the `lang:` or `language:` prefix the tag can also be followed by the
+/// words on the same line, e.g. `"lang:es abaco ..."`, and a tag that
+/// doesn't name a language returns [`Error::UnknownLanguage`]. Without the
+/// prefix only language names are recognized, and only when the line
+/// isn't itself a word, like `"italiano"`.
+///
+/// Phrases without a tag are detected with [`detect_with_checksum`].
+pub fn from_words_auto(phrase: &str) -> Result<(Language, Vec<u8>), Error> {
+    if let Some((language, words)) = split_language_tag(phrase)? {
+        return from_words(words, language).map(|bytes| (language, bytes));
+    }
+
+    let language = detect_with_checksum(phrase)?;
+    let bytes = from_words(phrase, language)?;
+
+    Ok((language, bytes))
+}
+
+/// Decodes a phrase under every available language.
+///
+/// Returns the languages the phrase decodes in, with the decoded seed.
+/// Unlike [`detect`] no language is ruled out for sharing words with
+/// another, so when detection is ambiguous this still finds the language
+/// whose checksum matches. The checksum is verified when the phrase has
+/// one.
+pub fn recover_language(phrase: &str) -> Vec<(Language, Vec<u8>)> {
+    Language::all()
+        .iter()
+        .filter(|language| language.is_available())
+        .filter_map(|&language| {
+            from_words(phrase, language)
+                .ok()
+                .map(|bytes| (language, bytes))
+        })
+        .collect()
+}
+
+/// Measures how often phrases of language `a` also match language `b`.
+///
+/// Encodes `samples` pseudo-random 32 byte seeds in `a` and returns the
+/// fraction of the phrases whose words are all in `b`, full or trimmed,
+/// i.e. phrases [`detect`] can't tell apart without the checksum. The
+/// seeds come from a fixed generator, so the result is reproducible.
+pub fn detection_collision_rate(a: Language, b: Language, samples: usize) -> f32 {
+    if samples == 0 || !a.is_available() || !b.is_available() {
+        return 0.0;
+    }
+
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut seed = [0u8; 32];
+    let mut collisions = 0;
+    for _ in 0..samples {
+        for byte in seed.iter_mut() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            *byte = state as u8;
+        }
+
+        let phrase = to_words(&seed, a).expect("valid seed length");
+        if phrase.split(' ').all(|word| b.lookup(word).is_some()) {
+            collisions += 1;
+        }
+    }
+
+    collisions as f32 / samples as f32
+}
+
+/// Checks whether two phrases encode the same seed, detecting their
+/// languages.
+///
+/// The phrases can be in different languages. The decoded seeds are
+/// compared in constant time and never returned, so a backup confirmation
+/// step doesn't expose the seed.
+#[must_use = "seed comparison result ignored"]
+pub fn same_entropy(a: &str, b: &str) -> Result<bool, Error> {
+    let (_, a) = from_words_auto(a)?;
+    let (_, b) = from_words_auto(b)?;
+
+    Ok(constant_time_eq(a.as_slice(), b.as_slice()))
+}
+
+/// The format of an English phrase, see [`classify_english`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum EnglishKind {
+    /// 24 words of the current English list, plus the checksum word if
+    /// present.
+    Modern24,
+    /// 12 words of the old English list, without checksum.
+    Old12,
+    /// 12 words of the old English list plus the checksum word.
+    Old13WithChecksum,
+    /// None of the above.
+    Unknown,
+}
+
+/// Guesses the format of an English phrase.
+///
+/// The current and old English lists share many words, so the word count,
+/// the list holding every word and the checksum are all taken into
+/// account to pick the decoding routine for legacy wallets.
+pub fn classify_english(phrase: &str) -> EnglishKind {
+    let words = phrase
+        .split_whitespace()
+        .take(MAX_WORDS + 1)
+        .collect::<Vec<_>>();
+    let all_in = |language: Language| words.iter().all(|word| language.lookup(word).is_some());
+
+    match words.len() {
+        24 if all_in(Language::English) => EnglishKind::Modern24,
+        25 if all_in(Language::English) && from_words(phrase, Language::English).is_ok() => {
+            EnglishKind::Modern24
+        }
+        12 if all_in(Language::EnglishOld) => EnglishKind::Old12,
+        13 if all_in(Language::EnglishOld) && from_words(phrase, Language::EnglishOld).is_ok() => {
+            EnglishKind::Old13WithChecksum
+        }
+        _ => EnglishKind::Unknown,
+    }
+}
+
+/// Returns the languages whose script matches every character of a phrase.
+///
+/// A cheap pre-filter for [`detect`]: Latin input can't be Russian, Chinese
+/// or Japanese, and the other way around. Whitespace is ignored, and a
+/// character of no known script, or a phrase mixing scripts, rules out
+/// every language. The words themselves aren't looked up.
+pub fn plausible_languages(phrase: &str) -> Vec<Language> {
+    let mut scripts = Vec::with_capacity(1);
+    for c in phrase.chars().filter(|c| !c.is_whitespace()) {
+        match char_script(c) {
+            Some(script) if !scripts.contains(&script) => scripts.push(script),
+            Some(_) => {}
+            None => return Vec::new(),
+        }
+    }
+
+    Language::all()
+        .iter()
+        .cloned()
+        .filter(|language| scripts.iter().all(|&script| script == language.script()))
+        .collect()
+}
+
+/// Returns the script of a character, by Unicode block.
+fn char_script(c: char) -> Option<Script> {
+    match c {
+        // Basic Latin to Latin Extended-B, and Latin Extended Additional.
+        '\u{0}'..='\u{24f}' | '\u{1e00}'..='\u{1eff}' => Some(Script::Latin),
+        '\u{400}'..='\u{4ff}' => Some(Script::Cyrillic),
+        // Hiragana and Katakana.
+        '\u{3040}'..='\u{30ff}' => Some(Script::Kana),
+        // CJK Unified Ideographs and Extension A.
+        '\u{3400}'..='\u{4dbf}' | '\u{4e00}'..='\u{9fff}' => Some(Script::Han),
+        _ => None,
+    }
+}
+
+/// Returns the languages whose word list holds every word of the phrase.
+///
+/// Returns [`Error::MixedLanguages`] or [`Error::NoLanguageMatch`] instead
+/// of an empty list.
+fn candidates(phrase: &str) -> Result<Vec<Language>, Error> {
+    // Counted first so an oversized phrase is never collected.
+    let count = phrase.split_whitespace().count();
+    if count == 0 {
+        return Err(Error::EmptyPhrase);
+    }
+
+    if count > MAX_WORDS {
+        return Err(Error::InvalidWordCount { found: count });
+    }
+
+    let words = phrase.split_whitespace().collect::<Vec<_>>();
+
+    let candidates = plausible_languages(phrase)
+        .into_iter()
+        .filter(|language| words.iter().all(|word| language.lookup(word).is_some()))
+        .collect::<Vec<_>>();
+    debug!(
+        words = words.len(),
+        ?candidates,
+        "language detection candidates"
+    );
+
+    if candidates.is_empty() {
+        return Err(match mixed_languages(words.as_slice()) {
+            Some(mixed) => Error::MixedLanguages(mixed),
+            None => Error::NoLanguageMatch,
+        });
+    }
+
+    Ok(candidates)
+}
+
+/// Maps each word to a language for phrases mixing languages.
+///
+/// Words are mapped to the language holding most of the words when it has
+/// them, and to the first language holding them otherwise. Returns `None`
+/// if any word isn't in any language.
+fn mixed_languages(words: &[&str]) -> Option<Vec<(usize, Language)>> {
+    let matches = words
+        .iter()
+        .map(|word| {
+            Language::all()
+                .iter()
+                .cloned()
+                .filter(|language| language.lookup(word).is_some())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    if matches.iter().any(|languages| languages.is_empty()) {
+        return None;
+    }
+
+    // On ties the first language in `Language::all` order wins.
+    let mut majority = Language::all()[0];
+    let mut majority_count = 0;
+    for &language in Language::all() {
+        let count = matches
+            .iter()
+            .filter(|languages| languages.contains(&language))
+            .count();
+        if count > majority_count {
+            majority = language;
+            majority_count = count;
+        }
+    }
+
+    Some(
+        matches
+            .iter()
+            .enumerate()
+            .map(|(i, languages)| {
+                if languages.contains(&majority) {
+                    (i, majority)
+                } else {
+                    (i, languages[0])
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Splits a leading language tag off a phrase, see [`from_words_auto`].
+fn split_language_tag(phrase: &str) -> Result<Option<(Language, &str)>, Error> {
+    let phrase = phrase.trim_start();
+    let (line, rest) = match phrase.find('\n') {
+        Some(end) => (&phrase[..end], Some(&phrase[end + 1..])),
+        None => (phrase, None),
+    };
+
+    let prefix = ["lang:", "language:"].iter().find(|prefix| {
+        line.get(..prefix.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+    });
+
+    let prefix = match prefix {
+        Some(prefix) => prefix,
+        None => {
+            let name = line.trim();
+            let rest = match rest {
+                Some(rest) if !Language::all().iter().any(|l| l.contains_word(name)) => rest,
+                _ => return Ok(None),
+            };
+
+            return Ok(Language::from_english_name(name).map(|language| (language, rest)));
+        }
+    };
+
+    // A tag on its own line can be a name with spaces, otherwise it's
+    // followed by the words on the same line.
+    let line = line[prefix.len()..].trim();
+    let (tag, words) = match rest {
+        Some(rest) => (line, rest),
+        None => line.split_at(line.find(char::is_whitespace).unwrap_or(line.len())),
+    };
+
+    match parse_tag(tag) {
+        Some(language) => Ok(Some((language, words))),
+        // Only the tag is reported, the line may hold seed words.
+        None => Err(Error::UnknownLanguage(tag.to_string())),
+    }
+}
+
+/// Parses a language name or code.
+fn parse_tag(tag: &str) -> Option<Language> {
+    Language::from_english_name(tag).or_else(|| Language::from_locale(tag))
+}
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use Language;
+
+/// The differences between a built-in word list and another list, see
+/// [`Language::wordlist_diff`](enum.Language.html#method.wordlist_diff).
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct WordlistDiff<'a> {
+    /// Words of the other list that aren't in the built-in one, in the
+    /// other list order.
+    pub added: Vec<&'a str>,
+    /// Words of the built-in list that aren't in the other one, in word
+    /// list order.
+    pub removed: Vec<&'static str>,
+    /// Words in both lists at different indices, with their index in the
+    /// built-in list and in the other list, in word list order.
+    pub moved: Vec<(&'static str, usize, usize)>,
+}
+
+impl<'a> WordlistDiff<'a> {
+    /// Compares the word list of `language` with `other`.
+    pub(crate) fn new(language: Language, other: &[&'a str]) -> WordlistDiff<'a> {
+        let wordlist = language.wordlist().unwrap_or(&[]);
+        let positions = other
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, &word)| (word, i))
+            .collect::<HashMap<_, _>>();
+
+        let mut diff = WordlistDiff {
+            added: other
+                .iter()
+                .cloned()
+                .filter(|word| language.lookup_exact(word).is_none())
+                .collect(),
+            ..WordlistDiff::default()
+        };
+
+        for (i, &word) in wordlist.iter().enumerate() {
+            match positions.get(word) {
+                None => diff.removed.push(word),
+                Some(&j) if j != i => diff.moved.push((word, i, j)),
+                Some(_) => {}
+            }
+        }
+
+        diff
+    }
+
+    /// Whether the lists have the same words in the same order.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.moved.is_empty()
+    }
+}
@@ -1,4 +1,4 @@
-pub const WORDLIST: &'static [&'static str] = &[
+pub const WORDLIST: &[&str] = &[
     "aalglad",
     "aalscholver",
     "aambeeld",
@@ -1626,3 +1626,5 @@ pub const WORDLIST: &'static [&'static str] = &[
     "zwijmel",
     "zworen",
 ];
+
+const _: () = assert!(WORDLIST.len() == ::WORDLIST_LENGTH);
@@ -1,4 +1,4 @@
-pub const WORDLIST: &'static [&'static str] = &[
+pub const WORDLIST: &[&str] = &[
     "like",
     "just",
     "love",
@@ -1626,3 +1626,5 @@ pub const WORDLIST: &'static [&'static str] = &[
     "weapon",
     "weary",
 ];
+
+const _: () = assert!(WORDLIST.len() == ::WORDLIST_LENGTH);
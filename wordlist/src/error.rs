@@ -0,0 +1,239 @@
+use std::error;
+use std::fmt;
+use std::ops::Range;
+
+use Language;
+
+/// Errors returned when encoding or decoding a seed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Error {
+    /// The seed length isn't a non-zero multiple of 4 bytes.
+    InvalidSeedLength {
+        /// The length of the seed in bytes.
+        len: usize,
+    },
+    /// The seed is longer than the supported maximum.
+    SeedTooLong {
+        /// The length of the seed in bytes.
+        len: usize,
+        /// The maximum length in bytes.
+        max: usize,
+    },
+    /// The seed is shorter than the length it should be truncated to.
+    SeedTooShort {
+        /// The length of the seed in bytes.
+        len: usize,
+        /// The minimum length in bytes.
+        min: usize,
+    },
+    /// The phrase has no words at all.
+    EmptyPhrase,
+    /// A hex encoded seed has an odd number of digits.
+    InvalidHexLength {
+        /// The number of hex digits.
+        len: usize,
+    },
+    /// A hex encoded seed has a character that isn't a hex digit.
+    InvalidHexCharacter {
+        /// The byte offset of the character.
+        index: usize,
+        /// The invalid character.
+        character: char,
+    },
+    /// The number of words can't form a valid phrase.
+    InvalidWordCount {
+        /// The number of words found.
+        found: usize,
+    },
+    /// The phrase is shorter than the shortest phrase with a checksum.
+    TooFewWordsForChecksum {
+        /// The number of words found, including the checksum word.
+        found: usize,
+        /// The minimum number of words,
+        /// [`MIN_CHECKSUM_WORDS`](crate::MIN_CHECKSUM_WORDS).
+        minimum: usize,
+    },
+    /// A word index is out of range for the word list.
+    InvalidWordIndex {
+        /// The position of the word in the phrase.
+        index: usize,
+        /// The out of range word list index, `usize::MAX` if it doesn't
+        /// even fit a `usize`.
+        value: usize,
+    },
+    /// A word isn't in the language word list.
+    UnknownWord {
+        /// The position of the word in the phrase.
+        index: usize,
+        /// The word as it was found in the phrase.
+        word: String,
+        /// The byte range of the word in the phrase, or the whole word
+        /// when the words are given separately.
+        span: Range<usize>,
+    },
+    /// Several words aren't in the language word list.
+    ///
+    /// Holds the position of every unknown word, the word as it was found
+    /// in the phrase and its byte range in the phrase. A single unknown
+    /// word is reported as [`Error::UnknownWord`] instead.
+    UnknownWords(Vec<(usize, String, Range<usize>)>),
+    /// A prefix matches more than one word of the word list.
+    AmbiguousWord {
+        /// The position of the word in the phrase.
+        index: usize,
+        /// The word as it was found in the phrase.
+        word: String,
+        /// The byte range of the word in the phrase.
+        span: Range<usize>,
+    },
+    /// A group of three words doesn't decode to a 32-bit value.
+    InvalidWordGroup {
+        /// The position of the first word of the group in the phrase.
+        index: usize,
+    },
+    /// The checksum word doesn't match the phrase.
+    InvalidChecksum,
+    /// The phrase doesn't match any language.
+    NoLanguageMatch,
+    /// The phrase matches more than one language.
+    AmbiguousLanguage(Vec<Language>),
+    /// The words of the phrase belong to different languages.
+    ///
+    /// Holds the position of every word and the language it belongs to.
+    MixedLanguages(Vec<(usize, Language)>),
+    /// A custom word list doesn't have the 1626 words the encoding needs.
+    InvalidWordlistLength {
+        /// The number of words of the list.
+        len: usize,
+    },
+    /// The unique prefix length of a custom word list is zero.
+    InvalidPrefixLength {
+        /// The unique prefix length.
+        len: usize,
+    },
+    /// A word of a custom word list is empty or contains whitespace.
+    InvalidWordlistWord {
+        /// The index of the word in the list.
+        index: usize,
+    },
+    /// Two words of a custom word list share the same unique prefix.
+    PrefixCollision {
+        /// The index of the first word in the list.
+        first: usize,
+        /// The index of the second word in the list.
+        second: usize,
+    },
+    /// A language name doesn't match any language.
+    UnknownLanguage(String),
+    /// The language feature is disabled, so its word list isn't available.
+    LanguageNotCompiled(Language),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::InvalidSeedLength { len } => {
+                write!(f, "invalid seed length of {} bytes", len)
+            }
+            Error::SeedTooLong { len, max } => {
+                write!(f, "seed of {} bytes is longer than {} bytes", len, max)
+            }
+            Error::SeedTooShort { len, min } => {
+                write!(f, "seed of {} bytes is shorter than {} bytes", len, min)
+            }
+            Error::InvalidHexLength { len } => {
+                write!(f, "hex seed has an odd number of digits ({})", len)
+            }
+            Error::InvalidHexCharacter { index, character } => write!(
+                f,
+                "invalid hex character {:?} at position {}",
+                character, index
+            ),
+            Error::EmptyPhrase => write!(f, "empty phrase"),
+            Error::InvalidWordCount { found } => write!(f, "invalid word count of {}", found),
+            Error::TooFewWordsForChecksum { found, minimum } => write!(
+                f,
+                "{} words are too few for a checksum, at least {} are needed",
+                found, minimum
+            ),
+            Error::InvalidWordIndex { index, value } => {
+                write!(
+                    f,
+                    "word index {} at position {} is out of range",
+                    value, index
+                )
+            }
+            Error::UnknownWord {
+                index, ref word, ..
+            } => {
+                write!(f, "unknown word \"{}\" at position {}", word, index)
+            }
+            Error::UnknownWords(ref words) => {
+                write!(f, "unknown words:")?;
+                for (i, &(index, ref word, _)) in words.iter().enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    write!(f, "{}\"{}\" at position {}", separator, word, index)?;
+                }
+                Ok(())
+            }
+            Error::AmbiguousWord {
+                index, ref word, ..
+            } => {
+                write!(f, "ambiguous word \"{}\" at position {}", word, index)
+            }
+            Error::InvalidWordGroup { index } => {
+                write!(f, "invalid word group starting at position {}", index)
+            }
+            Error::InvalidChecksum => write!(f, "invalid checksum word"),
+            Error::NoLanguageMatch => write!(f, "the phrase doesn't match any language"),
+            Error::AmbiguousLanguage(ref languages) => {
+                write!(f, "the phrase matches multiple languages:")?;
+                for (i, language) in languages.iter().enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    write!(f, "{}{}", separator, language.english_name())?;
+                }
+                Ok(())
+            }
+            Error::MixedLanguages(ref words) => {
+                write!(f, "the phrase mixes languages:")?;
+                for (i, &(index, language)) in words.iter().enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    write!(
+                        f,
+                        "{}word {} is {}",
+                        separator,
+                        index,
+                        language.english_name()
+                    )?;
+                }
+                Ok(())
+            }
+            Error::InvalidWordlistLength { len } => {
+                write!(f, "word list has {} words instead of 1626", len)
+            }
+            Error::InvalidPrefixLength { len } => {
+                write!(f, "invalid unique prefix length of {}", len)
+            }
+            Error::InvalidWordlistWord { index } => {
+                write!(
+                    f,
+                    "word {} of the word list is empty or has whitespace",
+                    index
+                )
+            }
+            Error::PrefixCollision { first, second } => write!(
+                f,
+                "words {} and {} of the word list share the same prefix",
+                first, second
+            ),
+            Error::UnknownLanguage(ref name) => write!(f, "unknown language \"{}\"", name),
+            Error::LanguageNotCompiled(language) => write!(
+                f,
+                "the {} word list isn't compiled in",
+                language.english_name()
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {}
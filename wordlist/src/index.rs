@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use {utf8prefix, Language, LANGUAGES};
+
+/// Reverse index of a word list, built lazily once per language.
+///
+/// The indexes are behind `OnceLock`s, so threads racing on first use
+/// block until one of them has built the index, which is built only once.
+pub struct Index {
+    /// Full words to their word list index.
+    words: HashMap<&'static str, u16>,
+    /// Words trimmed to the unique prefix length to their word list index.
+    ///
+    /// For word lists with shared prefixes the first word wins.
+    prefixes: HashMap<&'static str, u16>,
+    /// Words sorted by their bytes, for prefix searches.
+    sorted: Vec<&'static str>,
+    /// Length in bytes of the longest word.
+    max_len: usize,
+    /// Length in characters of the longest word.
+    max_chars: usize,
+    /// Total length in characters of all the words.
+    total_chars: usize,
+}
+
+static INDEXES: [OnceLock<Index>; LANGUAGES.len()] = [const { OnceLock::new() }; LANGUAGES.len()];
+
+impl Index {
+    /// Returns the index for the given language, building it on first use.
+    pub fn get(language: Language) -> &'static Index {
+        INDEXES[language as usize].get_or_init(|| Index::build(language))
+    }
+
+    fn build(language: Language) -> Index {
+        let wordlist = language.wordlist().unwrap_or(&[]);
+        let prefix_len = language.unique_prefix_len();
+
+        let mut words = HashMap::with_capacity(wordlist.len());
+        let mut prefixes = HashMap::with_capacity(wordlist.len());
+        for (i, word) in wordlist.iter().enumerate() {
+            words.insert(*word, i as u16);
+            prefixes
+                .entry(utf8prefix(word, prefix_len))
+                .or_insert(i as u16);
+        }
+
+        let mut sorted = wordlist.to_vec();
+        sorted.sort_unstable();
+
+        let max_len = wordlist.iter().map(|word| word.len()).max().unwrap_or(0);
+        let chars = wordlist.iter().map(|word| word.chars().count());
+        let max_chars = chars.clone().max().unwrap_or(0);
+        let total_chars = chars.sum();
+
+        Index {
+            words,
+            prefixes,
+            sorted,
+            max_len,
+            max_chars,
+            total_chars,
+        }
+    }
+
+    /// Finds the index of a full word.
+    pub fn exact(&self, word: &str) -> Option<u16> {
+        self.words.get(word).cloned()
+    }
+
+    /// Finds the index of a word trimmed to the unique prefix length.
+    pub fn prefix(&self, prefix: &str) -> Option<u16> {
+        self.prefixes.get(prefix).cloned()
+    }
+
+    /// Returns the words starting with `prefix`, sorted by their bytes.
+    ///
+    /// Binary searches the sorted words, so it's `O(log n + results)`.
+    pub fn starting_with(&self, prefix: &str) -> &[&'static str] {
+        let start = self.sorted.partition_point(|word| *word < prefix);
+        let len = self.sorted[start..]
+            .iter()
+            .take_while(|word| word.starts_with(prefix))
+            .count();
+
+        &self.sorted[start..start + len]
+    }
+
+    /// Returns the length in bytes of the longest word.
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Returns the length in characters of the longest word.
+    pub fn max_chars(&self) -> usize {
+        self.max_chars
+    }
+
+    /// Returns the total length in characters of all the words.
+    pub fn total_chars(&self) -> usize {
+        self.total_chars
+    }
+}
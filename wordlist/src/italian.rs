@@ -1,4 +1,4 @@
-pub const WORDLIST: &'static [&'static str] = &[
+pub const WORDLIST: &[&str] = &[
     "abbinare",
     "abbonato",
     "abisso",
@@ -1626,3 +1626,5 @@ pub const WORDLIST: &'static [&'static str] = &[
     "zombie",
     "zucchero",
 ];
+
+const _: () = assert!(WORDLIST.len() == ::WORDLIST_LENGTH);
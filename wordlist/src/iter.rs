@@ -0,0 +1,42 @@
+use std::slice;
+
+/// Iterator over the words of a language word list, in word list order.
+///
+/// Returned by [`Language::words_iter`](enum.Language.html#method.words_iter).
+#[derive(Debug, Clone)]
+pub struct WordlistIter {
+    inner: slice::Iter<'static, &'static str>,
+}
+
+impl WordlistIter {
+    /// Creates an iterator over the given word list.
+    pub(crate) fn new(wordlist: &'static [&'static str]) -> WordlistIter {
+        WordlistIter {
+            inner: wordlist.iter(),
+        }
+    }
+}
+
+impl Iterator for WordlistIter {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<&'static str> {
+        self.inner.next().cloned()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<&'static str> {
+        self.inner.nth(n).cloned()
+    }
+}
+
+impl DoubleEndedIterator for WordlistIter {
+    fn next_back(&mut self) -> Option<&'static str> {
+        self.inner.next_back().cloned()
+    }
+}
+
+impl ExactSizeIterator for WordlistIter {}
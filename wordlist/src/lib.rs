@@ -1,5 +1,4 @@
 #![deny(missing_docs)]
-#![feature(int_to_from_bytes)]
 
 //! Monero wordlists
 //!
@@ -8,29 +7,160 @@
 //!
 
 extern crate crc;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "secrecy")]
+extern crate secrecy;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "tracing")]
+extern crate tracing;
 
+// Instrumentation macros, no-ops unless the `tracing` feature is enabled.
+// Neither the seed bytes nor the words are ever passed to them.
+#[cfg(feature = "tracing")]
+macro_rules! debug {
+    ($($arg:tt)*) => { ::tracing::debug!($($arg)*) };
+}
+#[cfg(not(feature = "tracing"))]
+macro_rules! debug {
+    ($($arg:tt)*) => {};
+}
+#[cfg(feature = "tracing")]
+macro_rules! trace {
+    ($($arg:tt)*) => { ::tracing::trace!($($arg)*) };
+}
+#[cfg(not(feature = "tracing"))]
+macro_rules! trace {
+    ($($arg:tt)*) => {};
+}
+
+mod batch;
+mod detect;
+mod diff;
+mod error;
+mod index;
+mod iter;
+mod mnemonic;
+mod phrase;
+#[cfg(feature = "secrecy")]
+mod secret;
+mod source;
+
+#[cfg(feature = "chinese-simplified")]
 mod chinese_simplified;
+#[cfg(feature = "dutch")]
 mod dutch;
+#[cfg(feature = "english")]
 mod english;
+#[cfg(feature = "english-old")]
 mod english_old;
+#[cfg(feature = "esperanto")]
 mod esperanto;
+#[cfg(feature = "french")]
 mod french;
+#[cfg(feature = "german")]
 mod german;
+#[cfg(feature = "italian")]
 mod italian;
+#[cfg(feature = "japanese")]
 mod japanese;
+#[cfg(feature = "lojban")]
 mod lojban;
+#[cfg(feature = "portuguese")]
 mod portuguese;
+#[cfg(feature = "russian")]
 mod russian;
+#[cfg(feature = "spanish")]
 mod spanish;
 
+pub use batch::decode_batch;
+#[cfg(feature = "rayon")]
+pub use batch::decode_batch_par;
+pub use detect::{
+    analyze, classify_english, detect, detect_with_checksum, detection_collision_rate,
+    from_words_auto, plausible_languages, recover_language, same_entropy, DetectionResult,
+    EnglishKind,
+};
+pub use diff::WordlistDiff;
+pub use error::Error;
+pub use iter::WordlistIter;
+pub use mnemonic::{Mnemonic, Words};
+pub use phrase::{
+    normalize_phrase, ChecksumPolicy, ChecksumStatus, DecodeOptions, PhraseForm, EXTRA_SEPARATORS,
+};
+#[cfg(feature = "secrecy")]
+pub use secret::{from_secret_phrase, to_secret_words};
+pub use source::{CustomWordlist, WordlistSource};
+
+#[cfg(feature = "std")]
+use std::io;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use index::Index;
+
+/// Number of data words in a monero seed, not counting the checksum word.
+pub const DATA_WORD_COUNT: usize = 24;
+
+/// Number of words in a monero seed, including the checksum word.
+pub const TOTAL_WORD_COUNT: usize = DATA_WORD_COUNT + 1;
+
+/// Number of data words in a short 16 byte seed, as used by some forks,
+/// not counting the checksum word.
+pub const SHORT_DATA_WORD_COUNT: usize = 12;
+
+/// Number of words in a short 16 byte seed, including the checksum word.
+pub const SHORT_TOTAL_WORD_COUNT: usize = SHORT_DATA_WORD_COUNT + 1;
+
+/// Maximum seed length in bytes, the standard monero seed length.
+///
+/// Longer seeds are rejected with [`Error::SeedTooLong`] rather than
+/// encoded to phrases no wallet would accept.
+pub const MAX_SEED_LEN: usize = 32;
+
+/// Fewest words of a phrase with a checksum word, a group of 3 data words
+/// and the checksum word.
+pub const MIN_CHECKSUM_WORDS: usize = 4;
+
+/// Maximum number of words accepted when decoding a phrase.
+///
+/// Longer inputs are rejected before looking up any word.
+pub const MAX_WORDS: usize = TOTAL_WORD_COUNT;
+
 /// Numbers of words in a monero seed.
-pub const SEED_LENGTH: usize = 24;
+#[deprecated(note = "use `DATA_WORD_COUNT` or `TOTAL_WORD_COUNT` instead")]
+pub const SEED_LENGTH: usize = DATA_WORD_COUNT;
+
+/// Number of words in every word list.
+const WORDLIST_LENGTH: usize = 1626;
+
+/// Every supported language, whether its word list is compiled in or not.
+const LANGUAGES: &[Language] = &[
+    Language::ChineseSimplified,
+    Language::Dutch,
+    Language::English,
+    Language::EnglishOld,
+    Language::Esperanto,
+    Language::French,
+    Language::German,
+    Language::Italian,
+    Language::Japanese,
+    Language::Lojban,
+    Language::Portuguese,
+    Language::Russian,
+    Language::Spanish,
+];
 
 /// Word list type.
 pub type Wordlist = &'static [&'static str];
 
 /// The word list language.
+///
+/// These are all the word lists shipped by the Monero reference
+/// implementation, there are no Greek or Korean lists upstream.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub enum Language {
     /// Chinese (simplified) language.
     ChineseSimplified,
@@ -60,7 +190,285 @@ pub enum Language {
     Spanish,
 }
 
+/// The script a language is written in, see [`Language::script`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Script {
+    /// Latin script, possibly with diacritics.
+    Latin,
+    /// Cyrillic script.
+    Cyrillic,
+    /// Han characters.
+    Han,
+    /// Japanese kana.
+    Kana,
+    /// A mix of several scripts.
+    Mixed,
+}
+
+/// The writing direction of a language, see [`Language::writing_direction`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Direction {
+    /// Left to right.
+    Ltr,
+    /// Right to left.
+    Rtl,
+}
+
+/// The input method suited to entering a language's words, see
+/// [`Language::input_hint`].
+///
+/// Meant to be mapped to the keyboards or IMEs of the platform.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum InputHint {
+    /// A Latin keyboard, with diacritics for some languages.
+    Latin,
+    /// A Cyrillic keyboard.
+    Cyrillic,
+    /// Kana input.
+    Kana,
+    /// Pinyin input for Han characters.
+    Pinyin,
+}
+
+// Fails the build if more than one of the features is enabled.
+macro_rules! exclusive_features {
+    () => {};
+    ($first:literal $(, $rest:literal)*) => {
+        $(
+            #[cfg(all(feature = $first, feature = $rest))]
+            compile_error!(concat!(
+                "the `", $first, "` and `", $rest, "` features can't be enabled together"
+            ));
+        )*
+        exclusive_features!($($rest),*);
+    };
+}
+
+/// The default language without a `default-lang-*` feature: English if
+/// it's compiled in, the first compiled in language otherwise. Stays English
+/// if no language is compiled in at all.
+const FALLBACK_LANGUAGE: Language = {
+    const COMPILED: &[(bool, Language)] = &[
+        (cfg!(feature = "english"), Language::English),
+        (
+            cfg!(feature = "chinese-simplified"),
+            Language::ChineseSimplified,
+        ),
+        (cfg!(feature = "dutch"), Language::Dutch),
+        (cfg!(feature = "english-old"), Language::EnglishOld),
+        (cfg!(feature = "esperanto"), Language::Esperanto),
+        (cfg!(feature = "french"), Language::French),
+        (cfg!(feature = "german"), Language::German),
+        (cfg!(feature = "italian"), Language::Italian),
+        (cfg!(feature = "japanese"), Language::Japanese),
+        (cfg!(feature = "lojban"), Language::Lojban),
+        (cfg!(feature = "portuguese"), Language::Portuguese),
+        (cfg!(feature = "russian"), Language::Russian),
+        (cfg!(feature = "spanish"), Language::Spanish),
+    ];
+
+    let mut language = Language::English;
+    let mut i = 0;
+    while i < COMPILED.len() {
+        if COMPILED[i].0 {
+            language = COMPILED[i].1;
+            break;
+        }
+        i += 1;
+    }
+    language
+};
+
+// Defines `DEFAULT_LANGUAGE` from the `default-lang-*` features.
+macro_rules! default_language {
+    ($($feature:literal => $language:ident,)*) => {
+        /// The language returned by [`Language::default`].
+        const DEFAULT_LANGUAGE: Language = {
+            #[allow(unused_mut)]
+            let mut language = FALLBACK_LANGUAGE;
+            $(
+                if cfg!(feature = $feature) {
+                    language = Language::$language;
+                }
+            )*
+            language
+        };
+
+        exclusive_features!($($feature),*);
+    };
+}
+
+default_language! {
+    "default-lang-chinese-simplified" => ChineseSimplified,
+    "default-lang-dutch" => Dutch,
+    "default-lang-english-old" => EnglishOld,
+    "default-lang-esperanto" => Esperanto,
+    "default-lang-french" => French,
+    "default-lang-german" => German,
+    "default-lang-italian" => Italian,
+    "default-lang-japanese" => Japanese,
+    "default-lang-lojban" => Lojban,
+    "default-lang-portuguese" => Portuguese,
+    "default-lang-russian" => Russian,
+    "default-lang-spanish" => Spanish,
+}
+
+impl Default for Language {
+    /// Returns [`Language::English`], unless a `default-lang-*` feature,
+    /// e.g. `default-lang-japanese`, selects another language at compile
+    /// time. Without the `english` feature it's the first compiled in
+    /// language of [`Language::all`] instead.
+    fn default() -> Language {
+        DEFAULT_LANGUAGE
+    }
+}
+
+impl FromStr for Language {
+    type Err = Error;
+
+    /// Parses a language name, see [`Language::from_english_name`].
+    fn from_str(s: &str) -> Result<Language, Error> {
+        Language::from_english_name(s).ok_or_else(|| Error::UnknownLanguage(s.to_string()))
+    }
+}
+
+/// The language metadata, as returned by [`Language::describe`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+pub struct LanguageInfo {
+    /// The language name in English.
+    pub english_name: &'static str,
+    /// The language name in the language itself.
+    pub native_name: &'static str,
+    /// The ISO 639 code of the language.
+    pub iso_code: &'static str,
+    /// The number of words in the word list.
+    pub word_count: usize,
+    /// The unique prefix length of the words.
+    pub unique_prefix_len: usize,
+    /// Whether the language separates words with spaces when written.
+    pub uses_spaces: bool,
+    /// The script the words are written in.
+    pub script: Script,
+    /// The writing direction.
+    pub writing_direction: Direction,
+    /// The input method suited to entering the words.
+    pub input_hint: InputHint,
+}
+
 impl Language {
+    /// Returns every supported language, including the ones whose word
+    /// list isn't compiled in; see [`Language::is_available`].
+    pub fn all() -> &'static [Language] {
+        LANGUAGES
+    }
+
+    /// Returns the closest language for a BCP-47 or POSIX locale tag, e.g.
+    /// `"ja-JP"`, `"zh-Hans-CN"` or `"pt_BR.UTF-8"`.
+    ///
+    /// Only the primary language subtag is used, except for Chinese where
+    /// traditional script or regions don't match the simplified list. The
+    /// `en-x-old` tag selects the old English list.
+    pub fn from_locale(tag: &str) -> Option<Language> {
+        // Drop the POSIX encoding and modifier, e.g. `.UTF-8` or `@euro`.
+        let tag = tag.split(['.', '@']).next().unwrap_or("");
+        let mut subtags = tag
+            .split(['-', '_'])
+            .map(|subtag| subtag.to_ascii_lowercase());
+
+        let primary = subtags.next()?;
+        let rest = subtags.collect::<Vec<_>>();
+
+        let language = match primary.as_str() {
+            "zh" => {
+                let traditional = rest
+                    .iter()
+                    .any(|subtag| matches!(subtag.as_str(), "hant" | "tw" | "hk" | "mo"));
+                let simplified = rest.iter().any(|subtag| subtag == "hans");
+
+                if traditional && !simplified {
+                    return None;
+                }
+
+                Language::ChineseSimplified
+            }
+            "nl" => Language::Dutch,
+            "en" => {
+                if rest.windows(2).any(|w| w[0] == "x" && w[1] == "old") {
+                    Language::EnglishOld
+                } else {
+                    Language::English
+                }
+            }
+            "eo" => Language::Esperanto,
+            "fr" => Language::French,
+            "de" => Language::German,
+            "it" => Language::Italian,
+            "ja" => Language::Japanese,
+            "jbo" => Language::Lojban,
+            "pt" => Language::Portuguese,
+            "ru" => Language::Russian,
+            "es" => Language::Spanish,
+            _ => return None,
+        };
+
+        Some(language)
+    }
+
+    /// Returns the language with the given name, ignoring case and
+    /// surrounding whitespace.
+    ///
+    /// Accepts the English name, the native name and the
+    /// [`aliases`](#method.aliases) of each language, e.g. `"spanish"`,
+    /// `"Español"` or `"castilian"`.
+    pub fn from_english_name(name: &str) -> Option<Language> {
+        let name = name.trim().to_lowercase();
+
+        Language::all().iter().cloned().find(|language| {
+            language.english_name().to_lowercase() == name
+                || language.native_name().to_lowercase() == name
+                || language.aliases().contains(&name.as_str())
+        })
+    }
+
+    /// Returns the preferred language of an HTTP `Accept-Language` header
+    /// value, e.g. `"ja,en-US;q=0.8"`.
+    ///
+    /// Tags are tried by decreasing q-value, in header order for equal
+    /// values, and the first one [`from_locale`](#method.from_locale)
+    /// matches is returned. Tags with a zero or malformed q-value are
+    /// skipped.
+    pub fn from_accept_language(header: &str) -> Option<Language> {
+        let mut tags = header
+            .split(',')
+            .filter_map(|entry| {
+                let mut params = entry.split(';');
+                let tag = params.next()?.trim();
+                let mut quality = 1.0;
+                for param in params {
+                    let (name, value) = param.split_once('=')?;
+                    if name.trim().eq_ignore_ascii_case("q") {
+                        quality = value.trim().parse::<f32>().ok()?;
+                    }
+                }
+
+                if quality > 0.0 {
+                    Some((tag, quality))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // The sort is stable, so equal q-values keep the header order.
+        tags.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        tags.iter().find_map(|&(tag, _)| Language::from_locale(tag))
+    }
+
     /// Returns the language name in English.
     pub fn english_name(self) -> &'static str {
         match self {
@@ -80,25 +488,404 @@ impl Language {
         }
     }
 
+    /// Returns the language name in the language itself.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Language::ChineseSimplified => "简体中文 (中国)",
+            Language::Dutch => "Nederlands",
+            Language::English => "English",
+            Language::EnglishOld => "English (old)",
+            Language::Esperanto => "Esperanto",
+            Language::French => "Français",
+            Language::German => "Deutsch",
+            Language::Italian => "Italiano",
+            Language::Japanese => "日本語",
+            Language::Lojban => "Lojban",
+            Language::Portuguese => "Português",
+            Language::Russian => "русский язык",
+            Language::Spanish => "Español",
+        }
+    }
+
+    /// Returns common alternative names of the language, in lowercase.
+    ///
+    /// [`from_english_name`](#method.from_english_name) accepts them besides
+    /// the English and native names:
+    ///
+    /// | Language | Aliases |
+    /// |----------|---------|
+    /// | Chinese (simplified) | chinese, simplified chinese, chinese simplified, chinese-simplified, chinese_simplified, mandarin |
+    /// | Dutch | flemish |
+    /// | English (old) | old english, english old, english-old, english_old, legacy english |
+    /// | French | francais |
+    /// | Japanese | nihongo |
+    /// | Portuguese | portugues, brazilian, brazilian portuguese |
+    /// | Russian | russkiy |
+    /// | Spanish | espanol, castilian, castellano |
+    pub fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Language::ChineseSimplified => &[
+                "chinese",
+                "simplified chinese",
+                "chinese simplified",
+                "chinese-simplified",
+                "chinese_simplified",
+                "mandarin",
+            ],
+            Language::Dutch => &["flemish"],
+            Language::English => &[],
+            Language::EnglishOld => &[
+                "old english",
+                "english old",
+                "english-old",
+                "english_old",
+                "legacy english",
+            ],
+            Language::Esperanto => &[],
+            Language::French => &["francais"],
+            Language::German => &[],
+            Language::Italian => &[],
+            Language::Japanese => &["nihongo"],
+            Language::Lojban => &[],
+            Language::Portuguese => &["portugues", "brazilian", "brazilian portuguese"],
+            Language::Russian => &["russkiy"],
+            Language::Spanish => &["espanol", "castilian", "castellano"],
+        }
+    }
+
+    /// Returns the index of the language in the reference implementation
+    /// language list, as some wallet files and RPC calls store it.
+    ///
+    /// It's the order of `crypto::ElectrumWords`, starting at 0 with
+    /// German, which differs from the order of [`Language::all`].
+    pub fn monero_index(self) -> u8 {
+        match self {
+            Language::German => 0,
+            Language::English => 1,
+            Language::Spanish => 2,
+            Language::French => 3,
+            Language::Italian => 4,
+            Language::Dutch => 5,
+            Language::Portuguese => 6,
+            Language::Russian => 7,
+            Language::Japanese => 8,
+            Language::ChineseSimplified => 9,
+            Language::Esperanto => 10,
+            Language::Lojban => 11,
+            Language::EnglishOld => 12,
+        }
+    }
+
+    /// Returns the language with the given reference implementation index,
+    /// see [`monero_index`](#method.monero_index).
+    pub fn from_monero_index(index: u8) -> Option<Language> {
+        Language::all()
+            .iter()
+            .cloned()
+            .find(|language| language.monero_index() == index)
+    }
+
+    /// Returns the ISO 639 code of the language.
+    ///
+    /// The old English word list uses the `en-x-old` private use tag so it
+    /// can be told apart from the current one.
+    pub fn iso_code(self) -> &'static str {
+        match self {
+            Language::ChineseSimplified => "zh",
+            Language::Dutch => "nl",
+            Language::English => "en",
+            Language::EnglishOld => "en-x-old",
+            Language::Esperanto => "eo",
+            Language::French => "fr",
+            Language::German => "de",
+            Language::Italian => "it",
+            Language::Japanese => "ja",
+            Language::Lojban => "jbo",
+            Language::Portuguese => "pt",
+            Language::Russian => "ru",
+            Language::Spanish => "es",
+        }
+    }
+
+    /// Whether the language separates words with spaces when written.
+    ///
+    /// Phrases are always space separated regardless, this is meant for
+    /// rendering.
+    pub fn uses_spaces(self) -> bool {
+        !matches!(self, Language::ChineseSimplified | Language::Japanese)
+    }
+
+    /// Returns the script the words of the language are written in.
+    pub fn script(self) -> Script {
+        match self {
+            Language::ChineseSimplified => Script::Han,
+            Language::Japanese => Script::Kana,
+            Language::Russian => Script::Cyrillic,
+            _ => Script::Latin,
+        }
+    }
+
+    /// Returns the writing direction of the language.
+    ///
+    /// All the current languages are left to right.
+    pub fn writing_direction(self) -> Direction {
+        Direction::Ltr
+    }
+
+    /// Returns the input method suited to entering the words, e.g. to pick
+    /// the keyboard in mobile wallets.
+    pub fn input_hint(self) -> InputHint {
+        match self.script() {
+            Script::Han => InputHint::Pinyin,
+            Script::Kana => InputHint::Kana,
+            Script::Cyrillic => InputHint::Cyrillic,
+            Script::Latin | Script::Mixed => InputHint::Latin,
+        }
+    }
+
+    /// Returns all the language metadata at once.
+    pub fn describe(self) -> LanguageInfo {
+        LanguageInfo {
+            english_name: self.english_name(),
+            native_name: self.native_name(),
+            iso_code: self.iso_code(),
+            word_count: self.wordlist().map_or(0, |wordlist| wordlist.len()),
+            unique_prefix_len: self.unique_prefix_len(),
+            uses_spaces: self.uses_spaces(),
+            script: self.script(),
+            writing_direction: self.writing_direction(),
+            input_hint: self.input_hint(),
+        }
+    }
+
     /// Get the language word list.
-    pub fn wordlist(self) -> Wordlist {
+    ///
+    /// Returns [`Error::LanguageNotCompiled`] if the language feature is
+    /// disabled.
+    pub fn wordlist(self) -> Result<Wordlist, Error> {
         match self {
-            Language::ChineseSimplified => chinese_simplified::WORDLIST,
-            Language::Dutch => dutch::WORDLIST,
-            Language::English => english::WORDLIST,
-            Language::EnglishOld => english_old::WORDLIST,
-            Language::Esperanto => esperanto::WORDLIST,
-            Language::French => french::WORDLIST,
-            Language::German => german::WORDLIST,
-            Language::Italian => italian::WORDLIST,
-            Language::Japanese => japanese::WORDLIST,
-            Language::Lojban => lojban::WORDLIST,
-            Language::Portuguese => portuguese::WORDLIST,
-            Language::Russian => russian::WORDLIST,
-            Language::Spanish => spanish::WORDLIST,
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified => Ok(chinese_simplified::WORDLIST),
+            #[cfg(feature = "dutch")]
+            Language::Dutch => Ok(dutch::WORDLIST),
+            #[cfg(feature = "english")]
+            Language::English => Ok(english::WORDLIST),
+            #[cfg(feature = "english-old")]
+            Language::EnglishOld => Ok(english_old::WORDLIST),
+            #[cfg(feature = "esperanto")]
+            Language::Esperanto => Ok(esperanto::WORDLIST),
+            #[cfg(feature = "french")]
+            Language::French => Ok(french::WORDLIST),
+            #[cfg(feature = "german")]
+            Language::German => Ok(german::WORDLIST),
+            #[cfg(feature = "italian")]
+            Language::Italian => Ok(italian::WORDLIST),
+            #[cfg(feature = "japanese")]
+            Language::Japanese => Ok(japanese::WORDLIST),
+            #[cfg(feature = "lojban")]
+            Language::Lojban => Ok(lojban::WORDLIST),
+            #[cfg(feature = "portuguese")]
+            Language::Portuguese => Ok(portuguese::WORDLIST),
+            #[cfg(feature = "russian")]
+            Language::Russian => Ok(russian::WORDLIST),
+            #[cfg(feature = "spanish")]
+            Language::Spanish => Ok(spanish::WORDLIST),
+            #[allow(unreachable_patterns)]
+            _ => Err(Error::LanguageNotCompiled(self)),
+        }
+    }
+
+    /// Returns an iterator over the words of the word list.
+    ///
+    /// The iterator is empty if the language isn't available.
+    pub fn words_iter(self) -> WordlistIter {
+        WordlistIter::new(self.wordlist().unwrap_or(&[]))
+    }
+
+    /// Returns the numbered words of the word list, e.g. to print a
+    /// reference card.
+    ///
+    /// Numbers are the word list indices, starting at 0.
+    pub fn reference_card(self) -> impl ExactSizeIterator<Item = (u32, &'static str)> {
+        self.words_iter()
+            .enumerate()
+            .map(|(i, word)| (i as u32, word))
+    }
+
+    /// Same as [`reference_card`](#method.reference_card), but each word is
+    /// also paired with its unique prefix, the shorthand users can write
+    /// down instead of the full word.
+    pub fn reference_card_trimmed(
+        self,
+    ) -> impl ExactSizeIterator<Item = (u32, &'static str, &'static str)> {
+        let prefix_len = self.unique_prefix_len();
+        self.reference_card()
+            .map(move |(i, word)| (i, word, utf8prefix(word, prefix_len)))
+    }
+
+    /// Whether the language feature is enabled, so its word list is
+    /// available.
+    pub fn is_available(self) -> bool {
+        self.wordlist().is_ok()
+    }
+
+    /// Returns the base of the seed encoding, the number of words of the
+    /// word list.
+    ///
+    /// Every 4 bytes of a seed are 3 digits in this base. Returns 0 if the
+    /// language isn't available.
+    pub fn radix(self) -> u32 {
+        self.wordlist().map_or(0, |wordlist| wordlist.len() as u32)
+    }
+
+    /// Returns the word list index of a word, full or trimmed to the unique
+    /// prefix length.
+    pub fn word_index(self, word: &str) -> Option<u32> {
+        self.lookup(word).map(u32::from)
+    }
+
+    /// Returns the word at the given word list index.
+    ///
+    /// Returns `None` if the index is out of range or the language isn't
+    /// available, so indices read from untrusted storage can't panic.
+    pub fn word_at(self, index: u32) -> Option<&'static str> {
+        self.wordlist().ok()?.get(index as usize).cloned()
+    }
+
+    /// Whether `word` is a full word of the word list.
+    ///
+    /// Unlike [`word_index`](#method.word_index) this doesn't build the
+    /// lookup index: it scans the static word list, so it never allocates.
+    /// It's meant for validating words as they're typed, on every
+    /// keystroke.
+    pub fn contains_word(self, word: &str) -> bool {
+        self.wordlist()
+            .is_ok_and(|wordlist| wordlist.contains(&word))
+    }
+
+    /// Whether any word of the word list starts with `prefix`.
+    ///
+    /// Like [`contains_word`](#method.contains_word) it scans the static
+    /// word list and never allocates.
+    pub fn contains_prefix(self, prefix: &str) -> bool {
+        self.wordlist()
+            .is_ok_and(|wordlist| wordlist.iter().any(|w| w.starts_with(prefix)))
+    }
+
+    /// Returns the word list entry closest to a misspelled word.
+    ///
+    /// Words of the word list, full or trimmed, are returned as is.
+    /// Otherwise the word at the smallest edit distance (Levenshtein,
+    /// counted in characters) is returned if it's at most `max_distance`
+    /// away and no other word is as close. `None` uses
+    /// [`default_max_distance`](#method.default_max_distance).
+    pub fn closest_word(self, word: &str, max_distance: Option<usize>) -> Option<&'static str> {
+        let wordlist = self.wordlist().ok()?;
+        if let Some(index) = self.lookup(word) {
+            return Some(wordlist[index as usize]);
+        }
+
+        let max_distance = max_distance.unwrap_or_else(|| self.default_max_distance());
+        let mut closest = None;
+        let mut closest_distance = max_distance.saturating_add(1);
+        let mut tied = false;
+        for candidate in wordlist {
+            let distance = edit_distance(word, candidate);
+            if distance < closest_distance {
+                closest = Some(*candidate);
+                closest_distance = distance;
+                tied = false;
+            } else if distance == closest_distance {
+                tied = true;
+            }
+        }
+
+        if tied {
+            None
+        } else {
+            closest
+        }
+    }
+
+    /// Returns the default maximum edit distance of
+    /// [`closest_word`](#method.closest_word), half the unique prefix
+    /// length.
+    ///
+    /// That's 0 for Chinese, where any one character edit is already
+    /// another word, 1 for English and Japanese and 2 for the other
+    /// languages.
+    pub fn default_max_distance(self) -> usize {
+        self.unique_prefix_len() / 2
+    }
+
+    /// Returns the words starting with `prefix`, e.g. for autocompletion.
+    ///
+    /// The words are sorted by their bytes rather than in word list order.
+    /// A sorted copy of the word list is built on first use and binary
+    /// searched, so each call takes `O(log n + results)` comparisons instead
+    /// of a scan of the whole list.
+    pub fn words_with_prefix(self, prefix: &str) -> Vec<&'static str> {
+        Index::get(self).starting_with(prefix).to_vec()
+    }
+
+    /// Returns the length in characters of the longest word, e.g. to size
+    /// the columns of a seed grid.
+    ///
+    /// Returns 0 if the language isn't available.
+    pub fn max_word_len(self) -> usize {
+        Index::get(self).max_chars()
+    }
+
+    /// Returns the length in bytes of the longest word.
+    ///
+    /// Differs from [`max_word_len`](#method.max_word_len) for non-ASCII
+    /// word lists, e.g. Chinese words are a single character but three
+    /// bytes.
+    pub fn max_word_byte_len(self) -> usize {
+        Index::get(self).max_len()
+    }
+
+    /// Returns the average length in characters of the words.
+    ///
+    /// Returns 0 if the language isn't available.
+    pub fn average_word_len(self) -> f64 {
+        match self.wordlist() {
+            Ok(wordlist) => Index::get(self).total_chars() as f64 / wordlist.len() as f64,
+            Err(_) => 0.0,
         }
     }
 
+    /// Returns a fingerprint of the word list contents.
+    ///
+    /// It's the 64-bit FNV-1a hash of the words, each followed by a newline,
+    /// so it's stable across builds and platforms as long as the word list
+    /// doesn't change. Languages that aren't available hash as an empty
+    /// list.
+    pub fn wordlist_fingerprint(self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let mut hash = OFFSET_BASIS;
+        for word in self.wordlist().unwrap_or(&[]) {
+            for &byte in word.as_bytes().iter().chain(b"\n") {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(PRIME);
+            }
+        }
+
+        hash
+    }
+
+    /// Compares the word list with another one, e.g. a patched list
+    /// shipped by a fork.
+    ///
+    /// Reports the words only in `other`, the words missing from it and the
+    /// words at a different index. Duplicate words of `other` are compared
+    /// by their first occurrence.
+    pub fn wordlist_diff<'a>(self, other: &[&'a str]) -> WordlistDiff<'a> {
+        WordlistDiff::new(self, other)
+    }
+
     /// Returns the unique prefix length for the language.
     pub fn unique_prefix_len(&self) -> usize {
         match self {
@@ -117,68 +904,1068 @@ impl Language {
             Language::Spanish => 4,
         }
     }
+
+    /// Checks the word list invariants the encoding relies on.
+    ///
+    /// Verifies the word count, that there are no empty, padded or
+    /// duplicated words and that the unique prefix length is enough to tell
+    /// every word apart. Returns a description of every problem found.
+    pub fn self_check(self) -> Result<(), Vec<String>> {
+        let wordlist = self.wordlist().map_err(|e| vec![format!("{}", e)])?;
+        let prefix_len = self.unique_prefix_len();
+        let mut problems = Vec::new();
+
+        if wordlist.len() != WORDLIST_LENGTH {
+            problems.push(format!(
+                "{}: expected {} words, found {}",
+                self.english_name(),
+                WORDLIST_LENGTH,
+                wordlist.len()
+            ));
+        }
+
+        if prefix_len == 0 {
+            problems.push(format!(
+                "{}: unique prefix length is zero",
+                self.english_name()
+            ));
+        }
+
+        for (i, word) in wordlist.iter().enumerate() {
+            if word.is_empty() || word.trim() != *word {
+                problems.push(format!(
+                    "{}: word {} (\"{}\") is empty or has surrounding whitespace",
+                    self.english_name(),
+                    i,
+                    word
+                ));
+            }
+
+            if !self.allows_short_words() && word.chars().count() < prefix_len {
+                problems.push(format!(
+                    "{}: word {} (\"{}\") is shorter than the unique prefix length",
+                    self.english_name(),
+                    i,
+                    word
+                ));
+            }
+
+            for (j, other) in wordlist.iter().enumerate().skip(i + 1) {
+                if word == other {
+                    problems.push(format!(
+                        "{}: words {} and {} are both \"{}\"",
+                        self.english_name(),
+                        i,
+                        j,
+                        word
+                    ));
+                } else if !self.allows_duplicate_prefixes()
+                    && utf8prefix(word, prefix_len) == utf8prefix(other, prefix_len)
+                {
+                    problems.push(format!(
+                        "{}: words {} (\"{}\") and {} (\"{}\") share the same unique prefix",
+                        self.english_name(),
+                        i,
+                        word,
+                        j,
+                        other
+                    ));
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Whether the word list has words shorter than the unique prefix
+    /// length, as the reference implementation allows for some languages.
+    fn allows_short_words(self) -> bool {
+        matches!(self, Language::EnglishOld | Language::Spanish)
+    }
+
+    /// Whether the word list has words sharing the same unique prefix, as
+    /// the reference implementation allows for the old English list.
+    fn allows_duplicate_prefixes(self) -> bool {
+        self == Language::EnglishOld
+    }
+
+    /// Finds the index of a full word.
+    fn lookup_exact(self, word: &str) -> Option<u16> {
+        Index::get(self).exact(word)
+    }
+
+    /// Finds the index of a word, full or trimmed to the unique prefix
+    /// length.
+    ///
+    /// Exact matches take precedence over prefix matches, as some word
+    /// lists allow words sharing the same unique prefix.
+    fn lookup(self, word: &str) -> Option<u16> {
+        let index = Index::get(self);
+
+        index
+            .exact(word)
+            .or_else(|| index.prefix(utf8prefix(word, self.unique_prefix_len())))
+    }
 }
 
 /// Converts a given seed to words.
-pub fn to_words(bytes: &[u8], language: Language) -> String {
-    if bytes.len() % 4 != 0 || bytes.len() == 0 {
-        panic!("Invalid seed length");
+///
+/// A 32 byte seed gives [`TOTAL_WORD_COUNT`] words and a short 16 byte
+/// seed [`SHORT_TOTAL_WORD_COUNT`] words. The checksum word is picked
+/// among the data words, whatever their number.
+#[must_use = "the encoded phrase is returned, not stored"]
+pub fn to_words(bytes: &[u8], language: Language) -> Result<String, Error> {
+    let indices = to_indices(bytes, language)?;
+    Ok(join_words(indices.as_slice(), &language))
+}
+
+/// Converts a given seed to words, without the checksum word.
+///
+/// A 32 byte seed gives exactly [`DATA_WORD_COUNT`] words. [`from_words`]
+/// decodes phrases without the checksum word as well.
+pub fn to_words_no_checksum(bytes: &[u8], language: Language) -> Result<String, Error> {
+    let indices = to_indices(bytes, language)?;
+    let (_, data) = indices.split_last().expect("at least one word group");
+    Ok(join_words(data, &language))
+}
+
+/// Converts the first `target_len` bytes of a longer seed to words.
+///
+/// Meant for key material derived with other schemes, e.g. to encode the
+/// first 32 bytes of a 64 byte derived seed as a standard phrase. The
+/// remaining bytes are ignored. Returns [`Error::SeedTooShort`] if `bytes`
+/// has fewer than `target_len` bytes, and the same errors as [`to_words`]
+/// for the truncated seed.
+pub fn to_words_truncating(
+    bytes: &[u8],
+    language: Language,
+    target_len: usize,
+) -> Result<String, Error> {
+    if bytes.len() < target_len {
+        return Err(Error::SeedTooShort {
+            len: bytes.len(),
+            min: target_len,
+        });
     }
 
-    let wordlist = language.wordlist();
-    let wordlist_len = wordlist.len() as u32;
+    to_words(&bytes[..target_len], language)
+}
 
-    let mut words = String::new();
-    // To store the words for random access to add the checksum word later.
-    let mut words_store = Vec::with_capacity(SEED_LENGTH);
+/// Converts a given seed to numbered words, e.g. `"1. abbey"`, for guided
+/// write-down flows.
+///
+/// Numbering begins at `start`, usually 1, and includes the checksum word,
+/// the last entry, so with `start` 1 the checksum word of a 32 byte seed is
+/// number 25.
+pub fn to_numbered_words(
+    bytes: &[u8],
+    language: Language,
+    start: usize,
+) -> Result<Vec<String>, Error> {
+    let wordlist = language.wordlist()?;
+    let indices = to_indices(bytes, language)?;
 
-    // 4 bytes -> 3 words.  8 digits base 16 -> 3 digits base 1626
-    for i in 0..(bytes.len() / 4) {
-        let val = slice_to_le32(&bytes[(i * 4)..4]);
-        let w1 = val % wordlist_len;
-        let w2 = ((val / wordlist_len) + w1) % wordlist_len;
-        let w3 = (((val / wordlist_len) / wordlist_len) + w2) % wordlist_len;
+    Ok(indices
+        .iter()
+        .enumerate()
+        .map(|(i, &index)| format!("{}. {}", start + i, wordlist[index as usize]))
+        .collect())
+}
 
-        words.push_str(wordlist[w1 as usize]);
-        words.push(' ');
-        words.push_str(wordlist[w2 as usize]);
-        words.push(' ');
-        words.push_str(wordlist[w3 as usize]);
+/// Writes the words of a given seed to `out`, separated by `sep`.
+///
+/// Same words as [`to_words`], checksum word last, without building the
+/// phrase in memory. Encoding errors, e.g. an invalid seed length, are
+/// returned as [`io::ErrorKind::InvalidInput`] errors holding the
+/// [`Error`].
+#[cfg(feature = "std")]
+pub fn write_words<W: io::Write + ?Sized>(
+    bytes: &[u8],
+    language: Language,
+    sep: &str,
+    out: &mut W,
+) -> io::Result<()> {
+    let encode = |error| io::Error::new(io::ErrorKind::InvalidInput, error);
+    let wordlist = language.wordlist().map_err(encode)?;
+    let indices = to_indices(bytes, language).map_err(encode)?;
 
-        words_store.push(wordlist[w1 as usize]);
-        words_store.push(wordlist[w2 as usize]);
-        words_store.push(wordlist[w3 as usize]);
+    for (i, &index) in indices.iter().enumerate() {
+        if i != 0 {
+            out.write_all(sep.as_bytes())?;
+        }
 
-        words.push(' ');
+        out.write_all(wordlist[index as usize].as_bytes())?;
     }
 
-    let index = checksum_index(words_store.as_slice(), language.unique_prefix_len());
-    words.push_str(words_store[index]);
+    Ok(())
+}
+
+fn join_words<S: WordlistSource + ?Sized>(indices: &[u16], source: &S) -> String {
+    let mut words = String::new();
+    for (i, &index) in indices.iter().enumerate() {
+        if i != 0 {
+            words.push(' ');
+        }
+
+        words.push_str(source.word(index as usize));
+    }
 
     words
 }
 
+/// Returns the phrase of the all-zero 32 byte seed, for onboarding demos
+/// and screenshots.
+///
+/// **Never use it for real funds**: the seed is public, anyone can spend
+/// from its wallet. The phrase is encoded with [`to_words`] on first use,
+/// so it's always a valid phrase. Returns an empty string if the language
+/// isn't available.
+pub fn demo_phrase(language: Language) -> &'static str {
+    static PHRASES: [OnceLock<String>; LANGUAGES.len()] =
+        [const { OnceLock::new() }; LANGUAGES.len()];
+
+    PHRASES[language as usize]
+        .get_or_init(|| to_words(&[0; MAX_SEED_LEN], language).unwrap_or_default())
+}
+
+/// Returns the maximum length in bytes of the phrase [`to_words`] returns
+/// for a seed of `byte_len` bytes.
+///
+/// It's an upper bound for the space separated output, assuming every word
+/// is the longest one of the word list, meant to preallocate buffers once.
+/// Lengths are in bytes, so CJK words count several bytes per character.
+pub fn encoded_capacity(byte_len: usize, language: Language) -> usize {
+    if byte_len == 0 {
+        return 0;
+    }
+
+    let words = byte_len.div_ceil(4) * 3 + 1;
+    words * Index::get(language).max_len() + (words - 1)
+}
+
+/// Converts a given phrase to the seed bytes.
+///
+/// Words can be given in full or trimmed to the language unique prefix
+/// length. The checksum word is optional, if present it's verified.
+///
+/// Every word is looked up before failing, so several unknown words are
+/// reported at once with [`Error::UnknownWords`].
+#[must_use = "seed validity result ignored"]
+pub fn from_words(phrase: &str, language: Language) -> Result<Vec<u8>, Error> {
+    from_words_with(phrase, language, |_, _| {})
+}
+
+/// Converts a given phrase to the seed bytes, reporting each word.
+///
+/// Same as [`from_words`], but `on_word` is called as each word is resolved
+/// with its position in the phrase and the canonical word list entry, e.g.
+/// to report progress in recovery tools.
+pub fn from_words_with<F>(
+    phrase: &str,
+    language: Language,
+    mut on_word: F,
+) -> Result<Vec<u8>, Error>
+where
+    F: FnMut(usize, &str),
+{
+    let wordlist = language.wordlist()?;
+    decode(phrase, &language, &DecodeOptions::default(), |i, index| {
+        on_word(i, wordlist[index as usize])
+    })
+    .map(|(bytes, _)| bytes)
+}
+
+/// Converts a given phrase to the seed bytes and its canonical words.
+///
+/// Same as [`from_words`], but also returns the full word list entry each
+/// word of the phrase resolved to, e.g. to redisplay a trimmed phrase in
+/// full.
+pub fn from_words_canonical(
+    phrase: &str,
+    language: Language,
+) -> Result<(Vec<u8>, Vec<&'static str>), Error> {
+    let wordlist = language.wordlist()?;
+    let mut words = Vec::with_capacity(TOTAL_WORD_COUNT);
+    let (bytes, _) = decode(phrase, &language, &DecodeOptions::default(), |_, index| {
+        words.push(wordlist[index as usize])
+    })?;
+
+    Ok((bytes, words))
+}
+
+/// Converts a given phrase to the seed bytes, using the given options.
+///
+/// Same as [`from_words`], but words can also be separated by the
+/// [`DecodeOptions::separators`] characters, decoding stops at the first
+/// unknown word with [`DecodeOptions::fail_fast`] and the checksum is
+/// handled according to [`DecodeOptions::checksum_policy`].
+pub fn from_words_with_options(
+    phrase: &str,
+    language: Language,
+    options: &DecodeOptions,
+) -> Result<Vec<u8>, Error> {
+    from_words_with_status(phrase, language, options).map(|(bytes, _)| bytes)
+}
+
+/// Converts a given phrase to the seed bytes, reporting how the checksum
+/// was handled.
+///
+/// Same as [`from_words_with_options`], but also returns whether the
+/// checksum matched, e.g. to warn about a damaged checksum word with
+/// [`ChecksumPolicy::Warn`].
+pub fn from_words_with_status(
+    phrase: &str,
+    language: Language,
+    options: &DecodeOptions,
+) -> Result<(Vec<u8>, ChecksumStatus), Error> {
+    language.wordlist()?;
+    decode(phrase, &language, options, |_, _| {})
+}
+
+/// Decodes a phrase, calling `on_word` with the position and word list
+/// index of each word as it's resolved.
+fn decode<S, F>(
+    phrase: &str,
+    source: &S,
+    options: &DecodeOptions,
+    mut on_word: F,
+) -> Result<(Vec<u8>, ChecksumStatus), Error>
+where
+    S: WordlistSource + ?Sized,
+    F: FnMut(usize, u16),
+{
+    let count = options.tokens(phrase).count();
+    if count == 0 {
+        return Err(Error::EmptyPhrase);
+    }
+
+    if count > MAX_WORDS {
+        return Err(Error::InvalidWordCount { found: count });
+    }
+
+    let mut indices = Vec::with_capacity(TOTAL_WORD_COUNT);
+    let mut unknown = Vec::new();
+
+    for (i, (word, span)) in options.spans(phrase).enumerate() {
+        match source.lookup(word) {
+            Some(index) => {
+                trace!(position = i, found = true, "word lookup");
+                on_word(i, index);
+                indices.push(index);
+            }
+            None => {
+                trace!(position = i, found = false, "word lookup");
+                if options.is_fail_fast() {
+                    return Err(Error::UnknownWord {
+                        index: i,
+                        word: word.to_string(),
+                        span,
+                    });
+                }
+                unknown.push((i, word.to_string(), span));
+            }
+        }
+    }
+
+    if unknown.len() == 1 {
+        let (index, word, span) = unknown.remove(0);
+        return Err(Error::UnknownWord { index, word, span });
+    }
+    if !unknown.is_empty() {
+        return Err(Error::UnknownWords(unknown));
+    }
+
+    decode_indices(indices.as_slice(), source, options.checksum())
+}
+
+/// Converts a given phrase to the seed bytes, with the checksum word at
+/// position `pos` instead of last.
+///
+/// Meant for recovery tools trying out mangled backups. The phrase must
+/// have a checksum word, so a phrase without one or a `pos` past the end
+/// returns [`Error::InvalidWordCount`]. Error positions refer to the words
+/// as given.
+pub fn from_words_checksum_at(
+    phrase: &str,
+    language: Language,
+    pos: usize,
+) -> Result<Vec<u8>, Error> {
+    // Counted first so an oversized phrase is never collected.
+    let count = phrase.split_whitespace().count();
+    if count == 0 {
+        return Err(Error::EmptyPhrase);
+    }
+
+    if count > MAX_WORDS || count % 3 != 1 || pos >= count {
+        return Err(Error::InvalidWordCount { found: count });
+    }
+
+    let words = phrase::spans(phrase, char::is_whitespace).collect::<Vec<_>>();
+
+    language.wordlist()?;
+    let mut indices = Vec::with_capacity(words.len());
+    for (i, (word, span)) in words.into_iter().enumerate() {
+        match language.lookup(word) {
+            Some(index) => indices.push(index),
+            None => {
+                return Err(Error::UnknownWord {
+                    index: i,
+                    word: word.to_string(),
+                    span,
+                })
+            }
+        }
+    }
+
+    let checksum = indices.remove(pos);
+    indices.push(checksum);
+
+    from_indices(indices.as_slice(), language)
+}
+
+/// Converts a mix of words and word list indices to the seed bytes.
+///
+/// Tokens that are a bare integer are taken as a word list index, as
+/// stored by some backup tools, anything else is looked up as a word like
+/// [`from_words`] does. Out of range indices return
+/// [`Error::InvalidWordIndex`].
+pub fn from_mixed(tokens: &[&str], language: Language) -> Result<Vec<u8>, Error> {
+    let wordlist = language.wordlist()?;
+
+    if tokens.is_empty() {
+        return Err(Error::EmptyPhrase);
+    }
+
+    if tokens.len() > MAX_WORDS {
+        return Err(Error::InvalidWordCount {
+            found: tokens.len(),
+        });
+    }
+
+    let mut indices = Vec::with_capacity(tokens.len());
+    for (i, token) in tokens.iter().enumerate() {
+        let index = if !token.is_empty() && token.bytes().all(|b| b.is_ascii_digit()) {
+            // Too many digits for a usize is out of range all the same.
+            let value = token.parse::<usize>().unwrap_or(usize::MAX);
+            if value >= wordlist.len() {
+                return Err(Error::InvalidWordIndex { index: i, value });
+            }
+
+            Some(value as u16)
+        } else {
+            language.lookup(token)
+        };
+
+        match index {
+            Some(index) => indices.push(index),
+            None => {
+                return Err(Error::UnknownWord {
+                    index: i,
+                    word: token.to_string(),
+                    span: 0..token.len(),
+                })
+            }
+        }
+    }
+
+    from_indices(indices.as_slice(), language)
+}
+
+/// Returns the position of the checksum word in a phrase, the last word.
+///
+/// The phrase is decoded first, so an invalid checksum is reported as
+/// [`Error::InvalidChecksum`]. Phrases without checksum word give
+/// [`Error::InvalidWordCount`].
+pub fn checksum_word_position(phrase: &str, language: Language) -> Result<usize, Error> {
+    from_words(phrase, language)?;
+
+    let count = phrase.split_whitespace().count();
+    if count % 3 != 1 {
+        return Err(Error::InvalidWordCount { found: count });
+    }
+
+    Ok(count - 1)
+}
+
+/// Returns the position of the data word the checksum word is a copy of.
+///
+/// It's the CRC32 of the data words trimmed to the unique prefix length,
+/// modulo the number of data words. The words can be full or trimmed and
+/// aren't looked up in the word list.
+///
+/// # Panics
+///
+/// Panics if `data_words` is empty.
+pub fn recompute_checksum_position(data_words: &[&str], language: Language) -> usize {
+    assert!(!data_words.is_empty(), "no data words");
+    checksum_index(data_words, language.unique_prefix_len())
+}
+
+/// Returns the checksum words possible for the given data words.
+///
+/// Whole groups of 3 data words, the data words of any phrase
+/// [`from_words`] decodes, have a single checksum word, returned in full.
+/// With any other number of words, e.g. the first words of a phrase being
+/// recovered, words the checksum depends on are missing. The checksum word
+/// is a copy of one of the data words, so every distinct word given is
+/// returned; it can be a copy of a missing word as well, which can't be
+/// listed.
+///
+/// Returns no words if `data_words` is empty, too long or has a word that
+/// isn't in the word list.
+pub fn possible_checksum_words(data_words: &[&str], language: Language) -> Vec<&'static str> {
+    let wordlist = match language.wordlist() {
+        Ok(wordlist) => wordlist,
+        Err(_) => return Vec::new(),
+    };
+
+    if data_words.is_empty() || data_words.len() > DATA_WORD_COUNT {
+        return Vec::new();
+    }
+
+    let mut words = Vec::with_capacity(data_words.len());
+    for word in data_words {
+        match language.lookup(word) {
+            Some(index) => words.push(wordlist[index as usize]),
+            None => return Vec::new(),
+        }
+    }
+
+    if is_data_word_count(words.len()) {
+        let checksum = words[checksum_index(words.as_slice(), language.unique_prefix_len())];
+        return vec![checksum];
+    }
+
+    let mut candidates = Vec::with_capacity(words.len());
+    for word in words {
+        if !candidates.contains(&word) {
+            candidates.push(word);
+        }
+    }
+
+    candidates
+}
+
+/// Checks whether a phrase decodes to a seed, including its checksum word
+/// when present.
+///
+/// Same as `from_words(phrase, language).is_ok()`, but tells what's
+/// checked and can't be ignored by mistake.
+#[must_use = "seed validity result ignored"]
+pub fn is_valid(phrase: &str, language: Language) -> bool {
+    from_words(phrase, language).is_ok()
+}
+
+/// Verifies the checksum word of a phrase, the last word.
+///
+/// The phrase must have the word count of a phrase [`to_words`] encodes:
+/// whole groups of 3 data words, up to [`DATA_WORD_COUNT`], and the
+/// checksum word. Phrases shorter than [`MIN_CHECKSUM_WORDS`] return
+/// [`Error::TooFewWordsForChecksum`], other word counts
+/// [`Error::InvalidWordCount`]. Returns `Ok(false)` if any word isn't in
+/// the word list.
+#[must_use = "seed validity result ignored"]
+pub fn verify_checksum(phrase: &str, language: Language) -> Result<bool, Error> {
+    let count = phrase.split_whitespace().count();
+    if count > MAX_WORDS {
+        return Err(Error::InvalidWordCount { found: count });
+    }
+
+    let words = phrase.split_whitespace().collect::<Vec<_>>();
+    let (checksum, data) = words.split_last().ok_or(Error::EmptyPhrase)?;
+
+    if words.len() < MIN_CHECKSUM_WORDS {
+        return Err(Error::TooFewWordsForChecksum {
+            found: words.len(),
+            minimum: MIN_CHECKSUM_WORDS,
+        });
+    }
+
+    if !is_data_word_count(data.len()) {
+        return Err(Error::InvalidWordCount { found: words.len() });
+    }
+
+    Ok(checksum_matches_word(data, checksum, language))
+}
+
+/// Checks a checksum word against already split data words.
+///
+/// Both the data words and the checksum word can be given in full or
+/// trimmed to the language unique prefix length. Returns `false` if any
+/// word isn't in the word list, or if the data words aren't whole groups
+/// of 3 up to [`DATA_WORD_COUNT`], see [`verify_checksum`].
+#[must_use = "seed validity result ignored"]
+pub fn checksum_matches_word(
+    data_words: &[&str],
+    provided_checksum: &str,
+    language: Language,
+) -> bool {
+    if !is_data_word_count(data_words.len()) {
+        return false;
+    }
+
+    let wordlist = match language.wordlist() {
+        Ok(wordlist) => wordlist,
+        Err(_) => return false,
+    };
+    let mut words = Vec::with_capacity(data_words.len());
+    for word in data_words {
+        match language.lookup(word) {
+            Some(index) => words.push(wordlist[index as usize]),
+            None => return false,
+        }
+    }
+
+    let checksum = match language.lookup(provided_checksum) {
+        Some(index) => wordlist[index as usize],
+        None => return false,
+    };
+
+    let prefix_len = language.unique_prefix_len();
+    let expected = words[checksum_index(words.as_slice(), prefix_len)];
+    utf8prefix(expected, prefix_len) == utf8prefix(checksum, prefix_len)
+}
+
+/// Trims every word of a phrase to the language unique prefix length.
+///
+/// The words aren't validated, see [`to_full`] for the inverse operation.
+pub fn to_trimmed(phrase: &str, language: Language) -> String {
+    let prefix_len = language.unique_prefix_len();
+
+    phrase
+        .split_whitespace()
+        .map(|word| utf8prefix(word, prefix_len))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Expands every word of a phrase, full or trimmed, to the full word list
+/// entry.
+///
+/// For any valid phrase `to_full(&to_trimmed(phrase, language), language)`
+/// is the phrase itself, except for the old English list where some words
+/// share the same unique prefix. Those prefixes return
+/// [`Error::AmbiguousWord`].
+pub fn to_full(phrase: &str, language: Language) -> Result<String, Error> {
+    let wordlist = language.wordlist()?;
+    let prefix_len = language.unique_prefix_len();
+    let mut words = Vec::new();
+
+    for (i, (word, span)) in phrase::spans(phrase, char::is_whitespace).enumerate() {
+        if let Some(index) = language.lookup_exact(word) {
+            words.push(wordlist[index as usize]);
+            continue;
+        }
+
+        let prefix = utf8prefix(word, prefix_len);
+        let mut matches = wordlist
+            .iter()
+            .filter(|w| utf8prefix(w, prefix_len) == prefix);
+
+        match (matches.next(), matches.next()) {
+            (Some(full), None) => words.push(full),
+            (Some(_), Some(_)) => {
+                return Err(Error::AmbiguousWord {
+                    index: i,
+                    word: word.to_string(),
+                    span,
+                })
+            }
+            (None, _) => {
+                return Err(Error::UnknownWord {
+                    index: i,
+                    word: word.to_string(),
+                    span,
+                })
+            }
+        }
+    }
+
+    Ok(words.join(" "))
+}
+
+/// Returns whether the words of a phrase are full, trimmed or a mix of
+/// both.
+///
+/// A word is trimmed when it's no longer than the unique prefix length.
+/// Words of the word list that short, e.g. every Chinese word, are the
+/// same in both forms and don't count towards either. A phrase where no
+/// word says otherwise, including an empty one, is [`PhraseForm::Full`].
+/// The words aren't validated.
+pub fn phrase_form(phrase: &str, language: Language) -> PhraseForm {
+    let prefix_len = language.unique_prefix_len();
+    let mut full = false;
+    let mut trimmed = false;
+
+    for word in phrase.split_whitespace() {
+        if utf8prefix(word, prefix_len) != word {
+            full = true;
+        } else if language.lookup_exact(word).is_none() {
+            trimmed = true;
+        }
+    }
+
+    match (full, trimmed) {
+        (true, true) => PhraseForm::Mixed,
+        (false, true) => PhraseForm::Trimmed,
+        _ => PhraseForm::Full,
+    }
+}
+
+/// Converts a hex encoded seed to words.
+///
+/// Both lowercase and uppercase hex digits are accepted.
+pub fn hex_to_words(hex: &str, language: Language) -> Result<String, Error> {
+    if !hex.len().is_multiple_of(2) {
+        return Err(Error::InvalidHexLength { len: hex.len() });
+    }
+
+    let mut bytes = Vec::with_capacity(hex.len() / 2);
+    let mut high = 0u8;
+    for (i, character) in hex.char_indices() {
+        let digit = match character.to_digit(16) {
+            Some(digit) => digit as u8,
+            None => {
+                return Err(Error::InvalidHexCharacter {
+                    index: i,
+                    character,
+                })
+            }
+        };
+
+        if i % 2 == 0 {
+            high = digit << 4;
+        } else {
+            bytes.push(high | digit);
+        }
+    }
+
+    to_words(bytes.as_slice(), language)
+}
+
+/// Converts a given phrase to the lowercase hex encoded seed.
+pub fn words_to_hex(phrase: &str, language: Language) -> Result<String, Error> {
+    let bytes = from_words(phrase, language)?;
+
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        hex.push_str(&format!("{:02x}", byte));
+    }
+
+    Ok(hex)
+}
+
+/// Converts a 16 byte seed stored as a `u128` to words.
+///
+/// The seed bytes are the little-endian bytes of `value`, so the least
+/// significant byte is the first one, giving [`SHORT_TOTAL_WORD_COUNT`]
+/// words.
+pub fn u128_to_words(value: u128, language: Language) -> Result<String, Error> {
+    to_words(&value.to_le_bytes(), language)
+}
+
+/// Converts a given phrase of a 16 byte seed to a `u128`.
+///
+/// The seed bytes are read as the little-endian bytes of the value, like
+/// [`u128_to_words`] writes them. Phrases of other seed lengths return
+/// [`Error::InvalidSeedLength`].
+pub fn words_to_u128(phrase: &str, language: Language) -> Result<u128, Error> {
+    let bytes = from_words(phrase, language)?;
+    if bytes.len() != 16 {
+        return Err(Error::InvalidSeedLength { len: bytes.len() });
+    }
+
+    let mut value = [0u8; 16];
+    value.copy_from_slice(bytes.as_slice());
+    Ok(u128::from_le_bytes(value))
+}
+
+/// Re-renders a phrase in a different language.
+///
+/// This works because the seed bytes are language independent, the phrase
+/// is decoded with `from` and encoded again with `to`. The data words keep
+/// their indices but the checksum word is computed again, so it usually
+/// differs.
+pub fn translate(phrase: &str, from: Language, to: Language) -> Result<String, Error> {
+    let bytes = from_words(phrase, from)?;
+    to_words(bytes.as_slice(), to)
+}
+
+/// Converts a given seed to word list indices.
+///
+/// The returned indices include the checksum word as the last element. The
+/// data word indices are language independent, but the checksum word isn't
+/// as it's computed from the words themselves.
+///
+/// Seeds longer than [`MAX_SEED_LEN`] are rejected with
+/// [`Error::SeedTooLong`].
+pub fn to_indices(bytes: &[u8], language: Language) -> Result<Vec<u16>, Error> {
+    language.wordlist()?;
+    encode_indices(bytes, &language)
+}
+
+/// Converts the given word list indices to the seed bytes.
+///
+/// The checksum word index is optional, if present it's verified.
+pub fn from_indices(indices: &[u16], language: Language) -> Result<Vec<u8>, Error> {
+    language.wordlist()?;
+    decode_indices(indices, &language, ChecksumPolicy::Require).map(|(bytes, _)| bytes)
+}
+
+/// Converts a given seed to indices of the given word list, the checksum
+/// word last.
+fn encode_indices<S: WordlistSource + ?Sized>(bytes: &[u8], source: &S) -> Result<Vec<u16>, Error> {
+    if !bytes.len().is_multiple_of(4) || bytes.is_empty() {
+        return Err(Error::InvalidSeedLength { len: bytes.len() });
+    }
+
+    if bytes.len() > MAX_SEED_LEN {
+        return Err(Error::SeedTooLong {
+            len: bytes.len(),
+            max: MAX_SEED_LEN,
+        });
+    }
+
+    let radix = source.radix();
+
+    let mut indices = Vec::with_capacity((bytes.len() / 4) * 3 + 1);
+    for group in bytes.chunks(4) {
+        indices.extend_from_slice(&encode_group(slice_to_le32(group), radix));
+    }
+
+    let words = indices
+        .iter()
+        .map(|&index| source.word(index as usize))
+        .collect::<Vec<_>>();
+    let index = checksum_index(words.as_slice(), source.unique_prefix_len());
+    let checksum = indices[index];
+    indices.push(checksum);
+
+    Ok(indices)
+}
+
+/// Converts indices of the given word list to the seed bytes, handling the
+/// checksum word if present according to `policy`.
+fn decode_indices<S: WordlistSource + ?Sized>(
+    indices: &[u16],
+    source: &S,
+    policy: ChecksumPolicy,
+) -> Result<(Vec<u8>, ChecksumStatus), Error> {
+    if indices.len() % 3 == 2 || !is_data_word_count(indices.len() - indices.len() % 3) {
+        return Err(Error::InvalidWordCount {
+            found: indices.len(),
+        });
+    }
+
+    let radix = source.radix();
+
+    for (i, &index) in indices.iter().enumerate() {
+        if u32::from(index) >= radix {
+            return Err(Error::InvalidWordIndex {
+                index: i,
+                value: index as usize,
+            });
+        }
+    }
+
+    let data_len = indices.len() - (indices.len() % 3);
+    let (data, checksum) = indices.split_at(data_len);
+
+    let mut bytes = Vec::with_capacity((data.len() / 3) * 4);
+
+    for (i, group) in data.chunks(3).enumerate() {
+        let val = decode_group([group[0], group[1], group[2]], radix)
+            .ok_or(Error::InvalidWordGroup { index: i * 3 })?;
+        bytes.extend_from_slice(&val.to_le_bytes());
+    }
+
+    let checksum = match checksum.first() {
+        None => return Ok((bytes, ChecksumStatus::Missing)),
+        Some(_) if policy == ChecksumPolicy::Ignore => {
+            return Ok((bytes, ChecksumStatus::Ignored));
+        }
+        Some(&checksum) => checksum,
+    };
+
+    let words = data
+        .iter()
+        .map(|&index| source.word(index as usize))
+        .collect::<Vec<_>>();
+    let prefix_len = source.unique_prefix_len();
+    let expected = data[checksum_index(words.as_slice(), prefix_len)];
+
+    let valid = utf8prefix(source.word(expected as usize), prefix_len)
+        == utf8prefix(source.word(checksum as usize), prefix_len);
+    debug!(words = data.len(), valid, "checksum verification");
+
+    match (valid, policy) {
+        (true, _) => Ok((bytes, ChecksumStatus::Valid)),
+        (false, ChecksumPolicy::Warn) => Ok((bytes, ChecksumStatus::Mismatch)),
+        (false, _) => Err(Error::InvalidChecksum),
+    }
+}
+
+/// Returns the words of `a` that are also in `b`, in `a` word list order.
+pub fn shared_words(a: Language, b: Language) -> Vec<&'static str> {
+    a.wordlist()
+        .unwrap_or(&[])
+        .iter()
+        .cloned()
+        .filter(|word| b.lookup_exact(word).is_some())
+        .collect()
+}
+
+/// Returns the words of `a` that aren't in `b`, in `a` word list order.
+///
+/// These are the words telling a phrase in `a` apart from one in `b`.
+pub fn unique_to(a: Language, b: Language) -> Vec<&'static str> {
+    a.wordlist()
+        .unwrap_or(&[])
+        .iter()
+        .cloned()
+        .filter(|word| b.lookup_exact(word).is_none())
+        .collect()
+}
+
+/// Converts a group of 4 seed bytes to 3 words.
+///
+/// It's the building block of [`to_words`], without the checksum word.
+/// Every group encodes, so it only fails with [`Error::LanguageNotCompiled`].
+pub fn bytes_to_words_group(
+    group: [u8; 4],
+    language: Language,
+) -> Result<[&'static str; 3], Error> {
+    let wordlist = language.wordlist()?;
+    let [w1, w2, w3] = encode_group(u32::from_le_bytes(group), language.radix());
+
+    Ok([
+        wordlist[w1 as usize],
+        wordlist[w2 as usize],
+        wordlist[w3 as usize],
+    ])
+}
+
+/// Converts a group of 3 words to 4 seed bytes.
+///
+/// It's the building block of [`from_words`]. Words can be given in full or
+/// trimmed to the language unique prefix length.
+pub fn words_group_to_bytes(group: [&str; 3], language: Language) -> Result<[u8; 4], Error> {
+    language.wordlist()?;
+
+    let mut indices = [0; 3];
+    for (i, word) in group.iter().enumerate() {
+        indices[i] = language.lookup(word).ok_or_else(|| Error::UnknownWord {
+            index: i,
+            word: word.to_string(),
+            span: 0..word.len(),
+        })?;
+    }
+
+    decode_group(indices, language.radix())
+        .map(u32::to_le_bytes)
+        .ok_or(Error::InvalidWordGroup { index: 0 })
+}
+
+/// Converts a 32-bit value to 3 word list indices.
+///
+/// 8 digits base 16 -> 3 digits base `radix`, each digit offset by the
+/// previous one. The sums are done in 64 bits, so they can't overflow
+/// whatever the value and radix.
+fn encode_group(val: u32, radix: u32) -> [u16; 3] {
+    let val = u64::from(val);
+    let radix = u64::from(radix);
+
+    let w1 = val % radix;
+    let w2 = ((val / radix) + w1) % radix;
+    let w3 = (((val / radix) / radix) + w2) % radix;
+
+    [w1 as u16, w2 as u16, w3 as u16]
+}
+
+/// Converts 3 word list indices, each less than `radix`, to a 32-bit
+/// value.
+///
+/// Returns `None` if the indices don't decode to a 32-bit value. The value
+/// is computed in 128 bits, which holds `radix` cubed for any 32-bit radix.
+fn decode_group(indices: [u16; 3], radix: u32) -> Option<u32> {
+    let radix = u128::from(radix);
+    let w1 = u128::from(indices[0]);
+    let w2 = u128::from(indices[1]);
+    let w3 = u128::from(indices[2]);
+
+    let val =
+        w1 + radix * (((radix - w1) + w2) % radix) + radix * radix * (((radix - w2) + w3) % radix);
+
+    if val > u128::from(u32::MAX) {
+        return None;
+    }
+
+    Some(val as u32)
+}
+
+/// Compares two byte slices in constant time for a given length.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 fn slice_to_le32(s: &[u8]) -> u32 {
     let mut bytes = [0u8; 4];
     bytes.copy_from_slice(&s[0..4]);
-    u32::from_bytes(bytes).to_le()
+    u32::from_le_bytes(bytes)
+}
+
+/// Whether `len` data words are whole groups of 3, at most
+/// [`DATA_WORD_COUNT`]: the data words of every phrase [`to_words`]
+/// encodes and [`from_words`] decodes.
+fn is_data_word_count(len: usize) -> bool {
+    len >= 3 && len.is_multiple_of(3) && len <= DATA_WORD_COUNT
 }
 
-fn checksum_index(wordlist: &[&'static str], unique_prefix_len: usize) -> usize {
+/// Returns the index of the data word used as checksum.
+///
+/// The index is the CRC32 of the trimmed data words modulo the number of
+/// data words, like the reference implementation does.
+fn checksum_index(words: &[&str], unique_prefix_len: usize) -> usize {
     let mut trimmed_words = String::new();
 
-    for word in wordlist {
-        if word.len() > unique_prefix_len {
-            trimmed_words.push_str(utf8prefix(word, unique_prefix_len));
-        } else {
-            trimmed_words.push_str(word);
-        }
+    for word in words {
+        trimmed_words.push_str(utf8prefix(word, unique_prefix_len));
     }
 
     let ck = crc::crc32::checksum_ieee(trimmed_words.as_bytes()) as usize;
-    ck % SEED_LENGTH
+    ck % words.len()
+}
+
+/// Returns the Levenshtein distance between two strings, in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
 }
 
-fn utf8prefix<'a>(s: &'a str, count: usize) -> &'a str {
-    s.split_at(count).0
+/// Returns the first `count` characters of `s`, or `s` if it's shorter.
+fn utf8prefix(s: &str, count: usize) -> &str {
+    match s.char_indices().nth(count) {
+        Some((i, _)) => &s[..i],
+        None => s,
+    }
 }
@@ -1,4 +1,4 @@
-pub const WORDLIST: &'static [&'static str] = &[
+pub const WORDLIST: &[&str] = &[
     "backi",
     "bacru",
     "badna",
@@ -1626,3 +1626,5 @@ pub const WORDLIST: &'static [&'static str] = &[
     "samtci",
     "snaxa'a",
 ];
+
+const _: () = assert!(WORDLIST.len() == ::WORDLIST_LENGTH);
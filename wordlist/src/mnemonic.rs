@@ -0,0 +1,129 @@
+use std::fmt;
+use std::slice;
+use std::str::FromStr;
+
+use {constant_time_eq, detect_with_checksum, from_words, to_indices, Error, Language};
+
+/// A seed together with its phrase in a given language.
+///
+/// The `Debug` output never shows the seed or the words, use
+/// [`Mnemonic::phrase`] or [`Mnemonic::as_bytes`] when the plaintext is
+/// really needed. Equality compares the seeds in constant time.
+#[derive(Clone)]
+pub struct Mnemonic {
+    language: Language,
+    bytes: Vec<u8>,
+    words: Vec<&'static str>,
+}
+
+impl Mnemonic {
+    /// Creates the mnemonic of a seed.
+    pub fn from_bytes(bytes: &[u8], language: Language) -> Result<Mnemonic, Error> {
+        let wordlist = language.wordlist()?;
+        let words = to_indices(bytes, language)?
+            .into_iter()
+            .map(|index| wordlist[index as usize])
+            .collect();
+
+        Ok(Mnemonic {
+            language,
+            bytes: bytes.to_vec(),
+            words,
+        })
+    }
+
+    /// Decodes a phrase, see [`from_words`].
+    ///
+    /// The stored words are the full word list entries, including the
+    /// checksum word even if the phrase didn't have it.
+    pub fn from_phrase(phrase: &str, language: Language) -> Result<Mnemonic, Error> {
+        let bytes = from_words(phrase, language)?;
+        Mnemonic::from_bytes(bytes.as_slice(), language)
+    }
+
+    /// Returns the language of the words.
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Returns the seed bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Returns the phrase, the words separated by a space.
+    pub fn phrase(&self) -> String {
+        self.words.join(" ")
+    }
+
+    /// Returns an iterator over the words, the checksum word is the last
+    /// one.
+    pub fn words(&self) -> Words<'_> {
+        Words {
+            inner: self.words.iter(),
+        }
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = Error;
+
+    /// Decodes a phrase, detecting its language with
+    /// [`detect_with_checksum`].
+    ///
+    /// Returns [`Error::AmbiguousLanguage`] or [`Error::NoLanguageMatch`]
+    /// when the language can't be told, and the decoding errors of
+    /// [`Mnemonic::from_phrase`] otherwise.
+    fn from_str(phrase: &str) -> Result<Mnemonic, Error> {
+        let language = detect_with_checksum(phrase)?;
+        Mnemonic::from_phrase(phrase, language)
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Mnemonic {{ language: {:?}, words: [REDACTED] }}",
+            self.language
+        )
+    }
+}
+
+impl PartialEq for Mnemonic {
+    fn eq(&self, other: &Mnemonic) -> bool {
+        self.language == other.language && constant_time_eq(&self.bytes, &other.bytes)
+    }
+}
+
+impl Eq for Mnemonic {}
+
+impl<'a> IntoIterator for &'a Mnemonic {
+    type Item = &'a str;
+    type IntoIter = Words<'a>;
+
+    fn into_iter(self) -> Words<'a> {
+        self.words()
+    }
+}
+
+/// Iterator over the words of a [`Mnemonic`], the checksum word is the last
+/// one.
+#[derive(Debug, Clone)]
+pub struct Words<'a> {
+    inner: slice::Iter<'a, &'static str>,
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.inner.next().cloned()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Words<'a> {}
@@ -0,0 +1,154 @@
+use std::ops::Range;
+
+/// Options for decoding a phrase.
+///
+/// Words are always separated by whitespace, [`DecodeOptions::separators`]
+/// adds extra separator characters for phrases exported by other tools.
+/// Whitespace is any Unicode white space, so phrases pasted with
+/// non-breaking (U+00A0), thin (U+2009) or ideographic (U+3000) spaces
+/// decode as well.
+///
+/// By default every word of the phrase is looked up so all the unknown
+/// words are reported at once, [`DecodeOptions::fail_fast`] stops at the
+/// first one instead.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct DecodeOptions {
+    separators: Vec<char>,
+    fail_fast: bool,
+    checksum: ChecksumPolicy,
+}
+
+/// How the checksum word is handled when decoding, see
+/// [`DecodeOptions::checksum_policy`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum ChecksumPolicy {
+    /// A checksum word that doesn't match fails the decoding.
+    #[default]
+    Require,
+    /// A checksum word that doesn't match is reported with
+    /// [`ChecksumStatus::Mismatch`], but the seed is still returned.
+    Warn,
+    /// The checksum word is discarded without being verified.
+    Ignore,
+}
+
+/// How the checksum word of a decoded phrase was handled.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ChecksumStatus {
+    /// The checksum word matches.
+    Valid,
+    /// The checksum word doesn't match, only with [`ChecksumPolicy::Warn`].
+    Mismatch,
+    /// The phrase has no checksum word.
+    Missing,
+    /// The checksum word wasn't verified, with [`ChecksumPolicy::Ignore`].
+    Ignored,
+}
+
+/// Whether the words of a phrase are full or trimmed, as returned by
+/// [`phrase_form`](crate::phrase_form).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PhraseForm {
+    /// Every word is given in full.
+    Full,
+    /// Every word is trimmed to the unique prefix length.
+    Trimmed,
+    /// Some words are full and others trimmed.
+    Mixed,
+}
+
+/// Common separators used by other tools besides whitespace.
+pub const EXTRA_SEPARATORS: &[char] = &[',', ';', '|'];
+
+impl DecodeOptions {
+    /// Creates the default options, separating words by whitespace only.
+    pub fn new() -> DecodeOptions {
+        DecodeOptions::default()
+    }
+
+    /// Sets the characters that separate words in addition to whitespace.
+    pub fn separators(mut self, separators: &[char]) -> DecodeOptions {
+        self.separators = separators.to_vec();
+        self
+    }
+
+    /// Sets whether decoding stops at the first unknown word.
+    ///
+    /// Meant for tight loops such as recovery brute force, where only
+    /// rejecting a phrase quickly matters. The error is then always
+    /// [`Error::UnknownWord`] rather than [`Error::UnknownWords`].
+    ///
+    /// [`Error::UnknownWord`]: crate::Error::UnknownWord
+    /// [`Error::UnknownWords`]: crate::Error::UnknownWords
+    pub fn fail_fast(mut self, fail_fast: bool) -> DecodeOptions {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// Whether decoding stops at the first unknown word.
+    pub(crate) fn is_fail_fast(&self) -> bool {
+        self.fail_fast
+    }
+
+    /// Sets how the checksum word is handled, [`ChecksumPolicy::Require`]
+    /// by default.
+    ///
+    /// Lenient policies are meant for recovery tools, e.g. when the last
+    /// word of a backup is damaged. Wallets should keep the default.
+    pub fn checksum_policy(mut self, policy: ChecksumPolicy) -> DecodeOptions {
+        self.checksum = policy;
+        self
+    }
+
+    /// Returns how the checksum word is handled.
+    pub(crate) fn checksum(&self) -> ChecksumPolicy {
+        self.checksum
+    }
+
+    /// Splits a phrase into its words.
+    pub(crate) fn tokens<'a>(&'a self, phrase: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.spans(phrase).map(|(word, _)| word)
+    }
+
+    /// Splits a phrase into its words, with their byte range in `phrase`.
+    pub(crate) fn spans<'a>(
+        &'a self,
+        phrase: &'a str,
+    ) -> impl Iterator<Item = (&'a str, Range<usize>)> + 'a {
+        spans(phrase, move |c| {
+            c.is_whitespace() || self.separators.contains(&c)
+        })
+    }
+}
+
+/// Splits a phrase into its words at the `is_separator` characters, with
+/// the byte range of each word in `phrase`.
+///
+/// Ranges are in bytes, not characters, so `&phrase[span]` is the word
+/// even for multibyte languages.
+pub(crate) fn spans<'a, F>(
+    phrase: &'a str,
+    is_separator: F,
+) -> impl Iterator<Item = (&'a str, Range<usize>)> + 'a
+where
+    F: Fn(char) -> bool + 'a,
+{
+    phrase
+        .split(is_separator)
+        .filter(|word| !word.is_empty())
+        .map(move |word| {
+            // The words are slices of the phrase, so their offset is the
+            // distance between the two pointers.
+            let start = word.as_ptr() as usize - phrase.as_ptr() as usize;
+            (word, start..start + word.len())
+        })
+}
+
+/// Normalizes a phrase to its words separated by a single space.
+///
+/// It's idempotent: normalizing a normalized phrase returns it unchanged.
+/// Only separators are touched, the characters of the words, including
+/// their Unicode normalization form, are kept as is.
+pub fn normalize_phrase(phrase: &str, options: &DecodeOptions) -> String {
+    options.tokens(phrase).collect::<Vec<_>>().join(" ")
+}
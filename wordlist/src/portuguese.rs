@@ -1,4 +1,4 @@
-pub const WORDLIST: &'static [&'static str] = &[
+pub const WORDLIST: &[&str] = &[
     "abaular",
     "abdominal",
     "abeto",
@@ -1626,3 +1626,5 @@ pub const WORDLIST: &'static [&'static str] = &[
     "zenite",
     "zumbi",
 ];
+
+const _: () = assert!(WORDLIST.len() == ::WORDLIST_LENGTH);
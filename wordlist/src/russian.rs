@@ -1,4 +1,4 @@
-pub const WORDLIST: &'static [&'static str] = &[
+pub const WORDLIST: &[&str] = &[
     "абажур",
     "абзац",
     "абонент",
@@ -1626,3 +1626,5 @@ pub const WORDLIST: &'static [&'static str] = &[
     "ячейка",
     "ящик",
 ];
+
+const _: () = assert!(WORDLIST.len() == ::WORDLIST_LENGTH);
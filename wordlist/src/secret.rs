@@ -0,0 +1,96 @@
+use secrecy::zeroize::Zeroize;
+use secrecy::{ExposeSecret, Secret};
+
+use {from_words, to_indices, Error, Language};
+
+/// Stands in for the words of a secret phrase in errors.
+const REDACTED: &str = "[REDACTED]";
+
+/// Converts a secret phrase to the secret seed bytes, see [`from_words`].
+///
+/// The seed is only ever returned wrapped in a `Secret`, so it's zeroized
+/// on drop. The words of [`Error::UnknownWord`], [`Error::UnknownWords`]
+/// and [`Error::AmbiguousWord`] are replaced with `[REDACTED]`, only their
+/// position and byte range in the phrase are kept.
+///
+/// The temporary buffers [`from_words`] decodes through aren't zeroized.
+pub fn from_secret_phrase(
+    phrase: &Secret<String>,
+    language: Language,
+) -> Result<Secret<Vec<u8>>, Error> {
+    from_words(phrase.expose_secret(), language)
+        .map(Secret::new)
+        .map_err(redact)
+}
+
+/// Converts a secret seed to the secret phrase, see
+/// [`to_words`](crate::to_words).
+///
+/// The phrase is only ever returned wrapped in a `Secret`, so it's zeroized
+/// on drop. The word indices are zeroized as well, and the phrase is built
+/// in a buffer of its final size, so no reallocation leaves words behind.
+/// The temporary list of words the checksum is computed from isn't
+/// zeroized.
+pub fn to_secret_words(
+    bytes: &Secret<Vec<u8>>,
+    language: Language,
+) -> Result<Secret<String>, Error> {
+    let wordlist = language.wordlist()?;
+    let mut indices = to_indices(bytes.expose_secret(), language)?;
+
+    let len = indices
+        .iter()
+        .map(|&index| wordlist[index as usize].len() + 1)
+        .sum::<usize>();
+    let mut words = String::with_capacity(len);
+    for (i, &index) in indices.iter().enumerate() {
+        if i != 0 {
+            words.push(' ');
+        }
+
+        words.push_str(wordlist[index as usize]);
+    }
+    indices.zeroize();
+
+    Ok(Secret::new(words))
+}
+
+/// Replaces the words of `error` with [`REDACTED`], zeroizing them.
+fn redact(error: Error) -> Error {
+    match error {
+        Error::UnknownWord {
+            index,
+            mut word,
+            span,
+        } => {
+            word.zeroize();
+            Error::UnknownWord {
+                index,
+                word: REDACTED.to_string(),
+                span,
+            }
+        }
+        Error::UnknownWords(words) => Error::UnknownWords(
+            words
+                .into_iter()
+                .map(|(index, mut word, span)| {
+                    word.zeroize();
+                    (index, REDACTED.to_string(), span)
+                })
+                .collect(),
+        ),
+        Error::AmbiguousWord {
+            index,
+            mut word,
+            span,
+        } => {
+            word.zeroize();
+            Error::AmbiguousWord {
+                index,
+                word: REDACTED.to_string(),
+                span,
+            }
+        }
+        error => error,
+    }
+}
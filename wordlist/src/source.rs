@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use {decode, encode_indices, join_words, utf8prefix, DecodeOptions, Error, Language};
+
+/// A word list seeds can be encoded with.
+///
+/// Implemented by [`Language`] for the built-in word lists and by
+/// [`CustomWordlist`] for user supplied ones. The trait is sealed: other
+/// word lists go through [`CustomWordlist::new`], which checks them.
+pub trait WordlistSource: private::Sealed {
+    /// Returns the number of words of the word list.
+    fn word_count(&self) -> usize;
+
+    /// Returns the base of the seed encoding, the number of words.
+    fn radix(&self) -> u32 {
+        self.word_count() as u32
+    }
+
+    /// Returns the word at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` isn't less than [`word_count`](#tymethod.word_count).
+    fn word(&self, index: usize) -> &str;
+
+    /// Returns the number of characters identifying a word.
+    fn unique_prefix_len(&self) -> usize;
+
+    /// Finds the index of a word, full or trimmed to the unique prefix
+    /// length.
+    fn lookup(&self, word: &str) -> Option<u16>;
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for ::Language {}
+    impl Sealed for super::CustomWordlist {}
+}
+
+impl WordlistSource for Language {
+    fn word_count(&self) -> usize {
+        self.wordlist().map_or(0, |wordlist| wordlist.len())
+    }
+
+    fn radix(&self) -> u32 {
+        Language::radix(*self)
+    }
+
+    fn word(&self, index: usize) -> &str {
+        self.wordlist().unwrap_or(&[])[index]
+    }
+
+    fn unique_prefix_len(&self) -> usize {
+        Language::unique_prefix_len(self)
+    }
+
+    fn lookup(&self, word: &str) -> Option<u16> {
+        Language::lookup(*self, word)
+    }
+}
+
+/// A user supplied word list, checked on construction.
+///
+/// Encodes and decodes seeds the same way as the built-in languages, so a
+/// valid custom list can't silently corrupt seeds.
+#[derive(Debug, Clone)]
+pub struct CustomWordlist {
+    words: Vec<String>,
+    unique_prefix_len: usize,
+    /// Full words to their word list index.
+    exact: HashMap<String, u16>,
+    /// Words trimmed to the unique prefix length to their word list index.
+    prefixes: HashMap<String, u16>,
+}
+
+impl CustomWordlist {
+    /// Creates a custom word list.
+    ///
+    /// The list must have exactly 1626 words, the radix of the monero seed
+    /// encoding, or [`Error::InvalidWordlistLength`] is returned. Words
+    /// must be non-empty and without whitespace, see
+    /// [`Error::InvalidWordlistWord`], and no two words can share the same
+    /// first `unique_prefix_len` characters, see [`Error::PrefixCollision`].
+    pub fn new(words: Vec<String>, unique_prefix_len: usize) -> Result<CustomWordlist, Error> {
+        if words.len() != ::WORDLIST_LENGTH {
+            return Err(Error::InvalidWordlistLength { len: words.len() });
+        }
+
+        if unique_prefix_len == 0 {
+            return Err(Error::InvalidPrefixLength {
+                len: unique_prefix_len,
+            });
+        }
+
+        let mut exact = HashMap::with_capacity(words.len());
+        let mut prefixes = HashMap::<String, u16>::with_capacity(words.len());
+        for (i, word) in words.iter().enumerate() {
+            if word.is_empty() || word.chars().any(char::is_whitespace) {
+                return Err(Error::InvalidWordlistWord { index: i });
+            }
+
+            let prefix = utf8prefix(word, unique_prefix_len);
+            if let Some(&first) = prefixes.get(prefix) {
+                return Err(Error::PrefixCollision {
+                    first: first as usize,
+                    second: i,
+                });
+            }
+
+            exact.insert(word.clone(), i as u16);
+            prefixes.insert(prefix.to_string(), i as u16);
+        }
+
+        Ok(CustomWordlist {
+            words,
+            unique_prefix_len,
+            exact,
+            prefixes,
+        })
+    }
+
+    /// Returns the words of the word list.
+    pub fn words(&self) -> &[String] {
+        self.words.as_slice()
+    }
+
+    /// Converts a given seed to words.
+    pub fn to_words(&self, bytes: &[u8]) -> Result<String, Error> {
+        let indices = encode_indices(bytes, self)?;
+        Ok(join_words(indices.as_slice(), self))
+    }
+
+    /// Converts a given phrase to the seed bytes.
+    ///
+    /// Words can be given in full or trimmed to the unique prefix length.
+    /// The checksum word is optional, if present it's verified.
+    pub fn from_words(&self, phrase: &str) -> Result<Vec<u8>, Error> {
+        decode(phrase, self, &DecodeOptions::default(), |_, _| {}).map(|(bytes, _)| bytes)
+    }
+}
+
+impl WordlistSource for CustomWordlist {
+    fn word_count(&self) -> usize {
+        self.words.len()
+    }
+
+    fn word(&self, index: usize) -> &str {
+        self.words[index].as_str()
+    }
+
+    fn unique_prefix_len(&self) -> usize {
+        self.unique_prefix_len
+    }
+
+    fn lookup(&self, word: &str) -> Option<u16> {
+        self.exact
+            .get(word)
+            .or_else(|| self.prefixes.get(utf8prefix(word, self.unique_prefix_len)))
+            .cloned()
+    }
+}
@@ -0,0 +1,72 @@
+extern crate xmr_wordlist;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::hint;
+
+use xmr_wordlist::Language;
+
+/// Counts the allocations made by the current thread.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Runs `f` and returns its result with the number of allocations it made.
+fn count_allocations<T, F: FnOnce() -> T>(f: F) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn contains_word_without_allocating() {
+    // The counter sees allocations at all.
+    let (_, allocations) = count_allocations(|| hint::black_box(Vec::<u8>::with_capacity(1)));
+    assert_eq!(allocations, 1);
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        let last = *language.wordlist().unwrap().last().unwrap();
+        let prefix = &last[..last.chars().next().unwrap().len_utf8()];
+        let (found, allocations) =
+            count_allocations(|| language.contains_word(last) && language.contains_prefix(prefix));
+        assert!(found, "{:?}", language);
+        assert_eq!(allocations, 0, "{:?}", language);
+    }
+
+    if cfg!(feature = "english") {
+        let (results, allocations) = count_allocations(|| {
+            [
+                Language::English.contains_word("zodiac"),
+                Language::English.contains_word("zod"),
+                Language::English.contains_word("xyzzy"),
+                Language::English.contains_prefix("zod"),
+                Language::English.contains_prefix(""),
+                Language::English.contains_prefix("xyz"),
+            ]
+        });
+        assert_eq!(results, [true, false, false, true, true, false]);
+        assert_eq!(allocations, 0);
+    }
+
+    if cfg!(feature = "spanish") {
+        let (found, allocations) = count_allocations(|| Language::Spanish.contains_word("ábaco"));
+        assert!(found);
+        assert_eq!(allocations, 0);
+    }
+}
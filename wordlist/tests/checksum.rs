@@ -0,0 +1,192 @@
+// Some imports are only used by the English tests.
+#![cfg_attr(not(feature = "english"), allow(unused_imports))]
+
+extern crate xmr_wordlist;
+
+use xmr_wordlist::{
+    checksum_word_position, from_words, possible_checksum_words, recompute_checksum_position,
+    to_trimmed, to_words, Error, Language, DATA_WORD_COUNT, SHORT_DATA_WORD_COUNT,
+    SHORT_TOTAL_WORD_COUNT, TOTAL_WORD_COUNT,
+};
+
+// The expected checksum words were computed independently, with the
+// algorithm of Monero's `electrum-words.cpp`: CRC32 of the data words
+// trimmed to their unique prefix (in characters), modulo the number of data
+// words.
+
+/// Seed number, checksum word and the word modulo 25 would pick instead,
+/// for the English phrases of the seeds built in
+/// `checksum_index_modulo_data_words`.
+#[cfg(feature = "english")]
+const MODULO_CHECKSUMS: &[(u8, &str, &str)] = &[
+    (0, "ongoing", "dawn"),
+    (1, "tribal", "taunts"),
+    (2, "situated", "selfish"),
+    (3, "beer", "sixteen"),
+    (4, "scenic", "diode"),
+    (5, "ugly", "nexus"),
+    (7, "public", "berries"),
+    (8, "idled", "altitude"),
+];
+
+/// Index and checksum word of the phrase of the seed `00 01 .. 1f`.
+const CHECKSUMS: &[(Language, usize, &str)] = &[
+    (Language::ChineseSimplified, 5, "拥"),
+    (Language::Dutch, 12, "voesenek"),
+    (Language::English, 15, "boxes"),
+    (Language::EnglishOld, 10, "bit"),
+    (Language::Esperanto, 2, "boato"),
+    (Language::French, 23, "cadeau"),
+    (Language::German, 10, "Darm"),
+    (Language::Italian, 4, "sinergia"),
+    (Language::Japanese, 6, "ざんしょ"),
+    (Language::Lojban, 10, "fanmo"),
+    (Language::Portuguese, 8, "maquinista"),
+    (Language::Russian, 11, "засыпать"),
+    (Language::Spanish, 2, "barro"),
+];
+
+/// Index and checksum word of the phrase of the seed `00 01 .. 0f`.
+const SHORT_CHECKSUMS: &[(Language, usize, &str)] = &[
+    (Language::ChineseSimplified, 0, "进"),
+    (Language::Dutch, 3, "extinctie"),
+    (Language::English, 11, "faxed"),
+    (Language::EnglishOld, 4, "process"),
+    (Language::Esperanto, 0, "albumo"),
+    (Language::French, 6, "lettre"),
+    (Language::German, 11, "Einöde"),
+    (Language::Italian, 11, "destino"),
+    (Language::Japanese, 5, "なにもの"),
+    (Language::Lojban, 5, "vepre"),
+    (Language::Portuguese, 7, "laxativo"),
+    (Language::Russian, 7, "оазис"),
+    (Language::Spanish, 4, "oyente"),
+];
+
+#[test]
+#[cfg(feature = "english")]
+fn checksum_index_modulo_data_words() {
+    for &(i, expected, modulo_25) in MODULO_CHECKSUMS {
+        let seed = (0..32u8)
+            .map(|j| j.wrapping_mul(31).wrapping_add(i))
+            .collect::<Vec<_>>();
+        let phrase = to_words(&seed, Language::English).unwrap();
+        let words = phrase.split(' ').collect::<Vec<_>>();
+        assert_eq!(words.len(), TOTAL_WORD_COUNT);
+
+        let (data, checksum) = words.split_at(DATA_WORD_COUNT);
+        assert_eq!(checksum[0], expected, "seed {}", i);
+        assert_ne!(checksum[0], modulo_25, "seed {}", i);
+        assert!(data.contains(&expected) && data.contains(&modulo_25));
+    }
+}
+
+/// Checks the checksum word of the seed `00 01 ..` of the given length,
+/// per language.
+fn check_checksums(seed_len: u8, data_words: usize, expected: &[(Language, usize, &str)]) {
+    let seed = (0..seed_len).collect::<Vec<_>>();
+    for &(language, index, word) in expected.iter().filter(|&&(l, _, _)| l.is_available()) {
+        let phrase = to_words(&seed, language).unwrap();
+        let words = phrase.split(' ').collect::<Vec<_>>();
+        assert_eq!(words.len(), data_words + 1);
+        let (data, checksum) = words.split_at(data_words);
+
+        assert_eq!(checksum[0], word, "{:?}", language);
+        assert_eq!(data[index], word, "{:?}", language);
+        assert_eq!(from_words(&phrase, language), Ok(seed.clone()));
+    }
+}
+
+#[test]
+fn checksum_index_per_language() {
+    check_checksums(32, DATA_WORD_COUNT, CHECKSUMS);
+}
+
+#[test]
+fn short_seed_checksum_index_per_language() {
+    check_checksums(16, SHORT_DATA_WORD_COUNT, SHORT_CHECKSUMS);
+}
+
+#[test]
+fn short_seed_round_trip() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        for i in 0..16u8 {
+            let seed = (0..16u8)
+                .map(|j| j.wrapping_mul(97).wrapping_add(i.wrapping_mul(13)))
+                .collect::<Vec<_>>();
+            let phrase = to_words(&seed, language).unwrap();
+            assert_eq!(phrase.split(' ').count(), SHORT_TOTAL_WORD_COUNT);
+            assert_eq!(from_words(&phrase, language), Ok(seed.clone()));
+
+            let data = phrase.rsplit_once(' ').unwrap().0;
+            assert_eq!(from_words(data, language), Ok(seed));
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn checksum_positions() {
+    let seed = (0..32u8).collect::<Vec<_>>();
+    for &(language, index, _) in CHECKSUMS.iter().filter(|&&(l, _, _)| l.is_available()) {
+        let phrase = to_words(&seed, language).unwrap();
+        assert_eq!(checksum_word_position(&phrase, language), Ok(24));
+
+        let words = phrase.split(' ').collect::<Vec<_>>();
+        let (checksum, data) = words.split_last().unwrap();
+        let position = recompute_checksum_position(data, language);
+        assert_eq!(data[position], *checksum);
+        assert_eq!(position, index, "{:?}", language);
+
+        let data = data.join(" ");
+        assert_eq!(
+            checksum_word_position(&data, language),
+            Err(Error::InvalidWordCount { found: 24 })
+        );
+    }
+
+    let short = to_words(&seed[..16], Language::English).unwrap();
+    assert_eq!(checksum_word_position(&short, Language::English), Ok(12));
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn checksum_word_candidates() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(71)).collect::<Vec<_>>();
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        for len in (4..=32).step_by(4) {
+            let phrase = to_words(&seed[..len], language).unwrap();
+            let words = phrase.split(' ').collect::<Vec<_>>();
+            let (checksum, data) = words.split_last().unwrap();
+
+            assert_eq!(possible_checksum_words(data, language), vec![*checksum]);
+
+            let trimmed = to_trimmed(&data.join(" "), language);
+            if language != Language::EnglishOld {
+                let trimmed = trimmed.split(' ').collect::<Vec<_>>();
+                assert_eq!(possible_checksum_words(&trimmed, language), vec![*checksum]);
+            }
+
+            for count in (1..data.len()).filter(|count| count % 3 != 0) {
+                let partial = possible_checksum_words(&data[..count], language);
+                assert!(!partial.is_empty() && partial.len() <= count);
+                assert!(partial.iter().all(|word| data[..count].contains(word)));
+            }
+        }
+    }
+
+    // The first 20 words of a phrase miss a word of the last group.
+    let phrase = to_words(&seed, Language::English).unwrap();
+    let words = phrase.split(' ').take(20).collect::<Vec<_>>();
+    let partial = possible_checksum_words(&words, Language::English);
+    assert!(partial.len() > 1, "{:?}", partial);
+
+    let english = Language::English;
+    assert!(possible_checksum_words(&[], english).is_empty());
+    assert!(possible_checksum_words(&["abbey", "xyzzy"], english).is_empty());
+    assert_eq!(
+        possible_checksum_words(&["abbey", "abbey"], english),
+        vec!["abbey"]
+    );
+}
@@ -0,0 +1,104 @@
+// The custom word lists are built from the English one.
+#![cfg(feature = "english")]
+
+extern crate xmr_wordlist;
+
+use xmr_wordlist::{from_words, to_words, CustomWordlist, Error, Language, WordlistSource};
+
+fn english() -> Vec<String> {
+    Language::English
+        .words_iter()
+        .map(|word| word.to_string())
+        .collect()
+}
+
+#[test]
+fn custom_wordlist_round_trip() {
+    let custom = CustomWordlist::new(english(), 3).unwrap();
+    let seed = (0..32u8).map(|i| i.wrapping_mul(37)).collect::<Vec<_>>();
+
+    let phrase = custom.to_words(&seed).unwrap();
+    assert_eq!(phrase, to_words(&seed, Language::English).unwrap());
+    assert_eq!(custom.from_words(&phrase), Ok(seed.clone()));
+    assert_eq!(from_words(&phrase, Language::English), Ok(seed));
+
+    let upper = english()
+        .iter()
+        .map(|word| word.to_uppercase())
+        .collect::<Vec<_>>();
+    let custom = CustomWordlist::new(upper, 3).unwrap();
+    let phrase = custom.to_words(&[1; 16]).unwrap();
+    assert!(custom.from_words(&phrase.to_lowercase()).is_err());
+    assert_eq!(custom.from_words(&phrase), Ok(vec![1; 16]));
+}
+
+/// The first words of `source`, looked up back from their prefixes.
+fn first_words<S: WordlistSource>(source: &S) -> Vec<(String, Option<u16>)> {
+    (0..3)
+        .map(|i| {
+            let word = source.word(i);
+            let prefix = word
+                .chars()
+                .take(source.unique_prefix_len())
+                .collect::<String>();
+            (word.to_string(), source.lookup(&prefix))
+        })
+        .collect()
+}
+
+#[test]
+fn wordlist_source_is_generic() {
+    let custom = CustomWordlist::new(english(), 3).unwrap();
+    assert_eq!(custom.word_count(), 1626);
+    assert_eq!(custom.radix(), Language::English.radix());
+    assert_eq!(first_words(&custom), first_words(&Language::English));
+    assert_eq!(first_words(&custom)[2], ("ability".to_string(), Some(2)));
+}
+
+#[test]
+fn custom_wordlist_invariants() {
+    let mut words = english();
+    words.pop();
+    assert_eq!(
+        CustomWordlist::new(words, 3).unwrap_err(),
+        Error::InvalidWordlistLength { len: 1625 }
+    );
+
+    assert_eq!(
+        CustomWordlist::new(english(), 0).unwrap_err(),
+        Error::InvalidPrefixLength { len: 0 }
+    );
+
+    // "abbey" and "abducts" share "ab".
+    assert_eq!(
+        CustomWordlist::new(english(), 2).unwrap_err(),
+        Error::PrefixCollision {
+            first: 0,
+            second: 1
+        }
+    );
+
+    let mut words = english();
+    words[10] = words[3].clone();
+    assert_eq!(
+        CustomWordlist::new(words, 3).unwrap_err(),
+        Error::PrefixCollision {
+            first: 3,
+            second: 10
+        }
+    );
+
+    let mut words = english();
+    words[7] = "two words".to_string();
+    assert_eq!(
+        CustomWordlist::new(words, 3).unwrap_err(),
+        Error::InvalidWordlistWord { index: 7 }
+    );
+
+    let mut words = english();
+    words[9] = String::new();
+    assert_eq!(
+        CustomWordlist::new(words, 3).unwrap_err(),
+        Error::InvalidWordlistWord { index: 9 }
+    );
+}
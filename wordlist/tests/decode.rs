@@ -0,0 +1,930 @@
+// Some imports are only used by the tests of optional languages.
+#![cfg_attr(
+    not(all(
+        feature = "chinese-simplified",
+        feature = "dutch",
+        feature = "english",
+        feature = "english-old",
+        feature = "french",
+        feature = "german",
+        feature = "italian",
+        feature = "japanese",
+        feature = "portuguese",
+        feature = "russian",
+        feature = "spanish"
+    )),
+    allow(unused_imports)
+)]
+
+extern crate xmr_wordlist;
+
+#[cfg(feature = "std")]
+use xmr_wordlist::write_words;
+use xmr_wordlist::{
+    bytes_to_words_group, checksum_matches_word, decode_batch, demo_phrase, detect,
+    detect_with_checksum, from_indices, from_mixed, from_words, from_words_canonical,
+    from_words_checksum_at, from_words_with, from_words_with_options, from_words_with_status,
+    hex_to_words, is_valid, normalize_phrase, phrase_form, to_full, to_indices, to_numbered_words,
+    to_trimmed, to_words, to_words_no_checksum, to_words_truncating, translate, u128_to_words,
+    verify_checksum, words_group_to_bytes, words_to_hex, words_to_u128, ChecksumPolicy,
+    ChecksumStatus, DecodeOptions, Error, Language, PhraseForm, DATA_WORD_COUNT, EXTRA_SEPARATORS,
+    MAX_SEED_LEN, MAX_WORDS, MIN_CHECKSUM_WORDS,
+};
+
+#[test]
+#[cfg(feature = "english")]
+fn reject_too_many_words() {
+    let phrase = vec!["abbey"; 100_000].join(" ");
+
+    assert_eq!(
+        from_words(&phrase, Language::English),
+        Err(Error::InvalidWordCount { found: 100_000 })
+    );
+    assert_eq!(
+        from_words_checksum_at(&phrase, Language::English, 0),
+        Err(Error::InvalidWordCount { found: 100_000 })
+    );
+    assert_eq!(
+        verify_checksum(&phrase, Language::English),
+        Err(Error::InvalidWordCount { found: 100_000 })
+    );
+    assert_eq!(
+        detect(&phrase),
+        Err(Error::InvalidWordCount { found: 100_000 })
+    );
+
+    let indices = vec![0u16; MAX_WORDS + 1];
+    assert_eq!(
+        from_indices(&indices, Language::English),
+        Err(Error::InvalidWordCount {
+            found: MAX_WORDS + 1
+        })
+    );
+}
+
+#[test]
+#[cfg(all(feature = "english", feature = "spanish"))]
+fn translate_english_to_spanish() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(73)).collect::<Vec<_>>();
+    let english = to_words(&seed, Language::English).unwrap();
+    let spanish = translate(&english, Language::English, Language::Spanish).unwrap();
+
+    assert_ne!(english, spanish);
+    assert_eq!(from_words(&english, Language::English).unwrap(), seed);
+    assert_eq!(from_words(&spanish, Language::Spanish).unwrap(), seed);
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn reject_empty_phrase() {
+    assert_eq!(from_words("", Language::English), Err(Error::EmptyPhrase));
+    assert_eq!(
+        from_words("   ", Language::English),
+        Err(Error::EmptyPhrase)
+    );
+    assert_eq!(
+        from_words(" \t\n ", Language::English),
+        Err(Error::EmptyPhrase)
+    );
+}
+
+#[test]
+#[cfg(all(feature = "english", feature = "english-old"))]
+fn trimmed_full_round_trip() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(151)).collect::<Vec<_>>();
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        if language == Language::EnglishOld {
+            continue;
+        }
+
+        let phrase = to_words(&seed, language).unwrap();
+        let trimmed = to_trimmed(&phrase, language);
+        assert_eq!(to_full(&trimmed, language).unwrap(), phrase);
+        assert_eq!(to_full(&phrase, language).unwrap(), phrase);
+    }
+
+    assert_eq!(
+        to_full("abbey xyz", Language::English),
+        Err(Error::UnknownWord {
+            index: 1,
+            word: "xyz".to_string(),
+            span: 6..9
+        })
+    );
+    assert_eq!(
+        to_full("love lovely", Language::EnglishOld),
+        Ok("love lovely".to_string())
+    );
+    assert_eq!(
+        to_full("lovel", Language::EnglishOld),
+        Err(Error::AmbiguousWord {
+            index: 0,
+            word: "lovel".to_string(),
+            span: 0..5
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn decode_with_separators() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(29)).collect::<Vec<_>>();
+    let phrase = to_words(&seed, Language::English).unwrap();
+    let options = DecodeOptions::new().separators(EXTRA_SEPARATORS);
+
+    let piped = phrase.replace(' ', "|");
+    assert_eq!(
+        from_words_with_options(&piped, Language::English, &options).unwrap(),
+        seed
+    );
+
+    let commas = phrase.replace(' ', ",\n");
+    assert_eq!(
+        from_words_with_options(&commas, Language::English, &options).unwrap(),
+        seed
+    );
+    assert_eq!(normalize_phrase(&commas, &options), phrase);
+
+    assert!(from_words(&piped, Language::English).is_err());
+}
+
+#[test]
+fn boundary_seeds_round_trip() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        for &byte in &[0u8, 0xff] {
+            let seed = [byte; 32];
+            let phrase = to_words(&seed, language).unwrap();
+            assert_eq!(from_words(&phrase, language).unwrap(), seed, "{}", phrase);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn no_checksum_round_trip() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(41)).collect::<Vec<_>>();
+    let phrase = to_words_no_checksum(&seed, Language::English).unwrap();
+
+    assert_eq!(phrase.split(' ').count(), DATA_WORD_COUNT);
+    assert!(to_words(&seed, Language::English)
+        .unwrap()
+        .starts_with(&phrase));
+    assert_eq!(from_words(&phrase, Language::English).unwrap(), seed);
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn hex_round_trip() {
+    let hex = "01a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f80";
+    let phrase = hex_to_words(hex, Language::English).unwrap();
+
+    assert_eq!(words_to_hex(&phrase, Language::English).unwrap(), hex);
+    assert_eq!(
+        hex_to_words(&hex.to_uppercase(), Language::English).unwrap(),
+        phrase
+    );
+    assert_eq!(
+        hex_to_words("abc", Language::English),
+        Err(Error::InvalidHexLength { len: 3 })
+    );
+    assert_eq!(
+        hex_to_words("00zz", Language::English),
+        Err(Error::InvalidHexCharacter {
+            index: 2,
+            character: 'z'
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn decode_mixed_words_and_indices() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(19)).collect::<Vec<_>>();
+    let indices = to_indices(&seed, Language::English).unwrap();
+    let phrase = to_words(&seed, Language::English).unwrap();
+
+    let numbers = indices.iter().map(|i| i.to_string()).collect::<Vec<_>>();
+    let mut tokens = phrase.split(' ').collect::<Vec<_>>();
+    for i in (0..tokens.len()).step_by(2) {
+        tokens[i] = numbers[i].as_str();
+    }
+
+    assert_eq!(from_mixed(&tokens, Language::English).unwrap(), seed);
+    assert_eq!(from_indices(&indices, Language::English).unwrap(), seed);
+
+    tokens[1] = "1626";
+    assert_eq!(
+        from_mixed(&tokens, Language::English),
+        Err(Error::InvalidWordIndex {
+            index: 1,
+            value: 1626
+        })
+    );
+    tokens[1] = "99999";
+    assert_eq!(
+        from_mixed(&tokens, Language::English),
+        Err(Error::InvalidWordIndex {
+            index: 1,
+            value: 99999
+        })
+    );
+    tokens[1] = "99999999999999999999999";
+    assert_eq!(
+        from_mixed(&tokens, Language::English),
+        Err(Error::InvalidWordIndex {
+            index: 1,
+            value: usize::MAX
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn report_all_unknown_words() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(41)).collect::<Vec<_>>();
+    let phrase = to_words(&seed, Language::English).unwrap();
+    let mut words = phrase.split(' ').collect::<Vec<_>>();
+    words[2] = "xyzzy";
+    words[20] = "qqqqq";
+    let broken = words.join(" ");
+    let first = broken.find("xyzzy").unwrap();
+    let second = broken.find("qqqqq").unwrap();
+
+    assert_eq!(
+        from_words(&broken, Language::English),
+        Err(Error::UnknownWords(vec![
+            (2, "xyzzy".to_string(), first..first + 5),
+            (20, "qqqqq".to_string(), second..second + 5),
+        ]))
+    );
+
+    let options = DecodeOptions::new().fail_fast(true);
+    assert_eq!(
+        from_words_with_options(&broken, Language::English, &options),
+        Err(Error::UnknownWord {
+            index: 2,
+            word: "xyzzy".to_string(),
+            span: first..first + 5
+        })
+    );
+
+    words[20] = "abbey";
+    let one_wrong = words.join(" ");
+    for options in &[DecodeOptions::new(), options] {
+        assert_eq!(
+            from_words_with_options(&one_wrong, Language::English, options),
+            Err(Error::UnknownWord {
+                index: 2,
+                word: "xyzzy".to_string(),
+                span: first..first + 5
+            })
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn reject_too_long_seed() {
+    assert!(to_words(&[7; MAX_SEED_LEN], Language::English).is_ok());
+    assert_eq!(
+        to_words(&[7; MAX_SEED_LEN + 4], Language::English),
+        Err(Error::SeedTooLong {
+            len: MAX_SEED_LEN + 4,
+            max: MAX_SEED_LEN
+        })
+    );
+    assert_eq!(
+        to_indices(&[7; 1024], Language::English),
+        Err(Error::SeedTooLong {
+            len: 1024,
+            max: MAX_SEED_LEN
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn truncate_derived_seed() {
+    let derived: Vec<u8> = (0..64).collect();
+    let phrase = to_words_truncating(&derived, Language::English, MAX_SEED_LEN).unwrap();
+    assert_eq!(
+        phrase,
+        to_words(&derived[..MAX_SEED_LEN], Language::English).unwrap()
+    );
+    assert_eq!(
+        from_words(&phrase, Language::English),
+        Ok(derived[..MAX_SEED_LEN].to_vec())
+    );
+
+    assert_eq!(
+        to_words_truncating(&derived[..20], Language::English, MAX_SEED_LEN),
+        Err(Error::SeedTooShort {
+            len: 20,
+            min: MAX_SEED_LEN
+        })
+    );
+    assert_eq!(
+        to_words_truncating(&derived, Language::English, 30),
+        Err(Error::InvalidSeedLength { len: 30 })
+    );
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn demo_phrase_round_trip() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        let phrase = demo_phrase(language);
+        assert_eq!(phrase.split(' ').count(), 25);
+        assert_eq!(from_words(phrase, language), Ok(vec![0; 32]));
+        assert!(std::ptr::eq(phrase, demo_phrase(language)));
+    }
+
+    assert!(demo_phrase(Language::English).starts_with("abbey abbey"));
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn canonical_words() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(53)).collect::<Vec<_>>();
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        // Old English words sharing a prefix can't be trimmed.
+        if language == Language::EnglishOld {
+            continue;
+        }
+
+        let phrase = to_words(&seed, language).unwrap();
+        let trimmed = to_trimmed(&phrase, language);
+
+        let (bytes, words) = from_words_canonical(&trimmed, language).unwrap();
+        assert_eq!(bytes, seed);
+        assert_eq!(words.join(" "), phrase);
+    }
+
+    assert_eq!(
+        from_words_canonical("abbey xyzzy", Language::English),
+        Err(Error::UnknownWord {
+            index: 1,
+            word: "xyzzy".to_string(),
+            span: 6..11
+        })
+    );
+}
+
+#[test]
+fn normalize_phrase_idempotent() {
+    // Letters in NFC and NFD forms, combining marks, Unicode spaces and
+    // characters that look like spaces but aren't whitespace.
+    const ALPHABET: &[&str] = &[
+        "a", "z", "\u{e9}", "e\u{301}", "\u{301}", "\u{308}", "ア", "的", " ", "  ", "\t", "\n",
+        "\r\n", "\u{a0}", "\u{2009}", "\u{3000}", "\u{200b}", "\u{feff}", ",", ";", "|", ", ",
+    ];
+
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mut phrases = vec![String::new(), " ".to_string(), "\u{3000}".to_string()];
+    for _ in 0..2000 {
+        let len = next() % 24;
+        let phrase = (0..len)
+            .map(|_| ALPHABET[(next() % ALPHABET.len() as u64) as usize])
+            .collect::<String>();
+        phrases.push(phrase);
+    }
+
+    let options = [
+        DecodeOptions::new(),
+        DecodeOptions::new().separators(EXTRA_SEPARATORS),
+    ];
+    for phrase in &phrases {
+        for options in &options {
+            let normalized = normalize_phrase(phrase, options);
+            assert_eq!(
+                normalize_phrase(&normalized, options),
+                normalized,
+                "{:?}",
+                phrase
+            );
+            assert!(!normalized.starts_with(' ') && !normalized.ends_with(' '));
+            assert!(!normalized.contains("  "));
+        }
+    }
+}
+
+#[test]
+#[cfg(all(
+    feature = "chinese-simplified",
+    feature = "french",
+    feature = "japanese",
+    feature = "spanish"
+))]
+fn decode_unicode_spaces() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(61)).collect::<Vec<_>>();
+    let cases = [
+        (Language::Japanese, "\u{3000}"),
+        (Language::Spanish, "\u{a0}"),
+        (Language::French, "\u{2009}"),
+        (Language::ChineseSimplified, "\u{3000}\u{a0}"),
+    ];
+
+    for &(language, space) in &cases {
+        let phrase = to_words(&seed, language).unwrap();
+        let glued = phrase.replace(' ', space);
+        assert_eq!(from_words(&glued, language), Ok(seed.clone()));
+        assert_eq!(detect_with_checksum(&glued), Ok(language));
+        assert_eq!(normalize_phrase(&glued, &DecodeOptions::new()), phrase);
+    }
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn checksum_policies() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(67)).collect::<Vec<_>>();
+    let phrase = to_words(&seed, Language::English).unwrap();
+    let (data, checksum) = phrase.rsplit_once(' ').unwrap();
+    let wrong = if checksum == "abbey" { "zoom" } else { "abbey" };
+    let damaged = format!("{} {}", data, wrong);
+
+    let status = |phrase: &str, policy| {
+        let options = DecodeOptions::new().checksum_policy(policy);
+        from_words_with_status(phrase, Language::English, &options)
+    };
+
+    assert_eq!(
+        status(&phrase, ChecksumPolicy::Require),
+        Ok((seed.clone(), ChecksumStatus::Valid))
+    );
+    assert_eq!(
+        status(&damaged, ChecksumPolicy::Require),
+        Err(Error::InvalidChecksum)
+    );
+    assert_eq!(
+        status(&damaged, ChecksumPolicy::Warn),
+        Ok((seed.clone(), ChecksumStatus::Mismatch))
+    );
+    assert_eq!(
+        status(&phrase, ChecksumPolicy::Warn),
+        Ok((seed.clone(), ChecksumStatus::Valid))
+    );
+    assert_eq!(
+        status(&damaged, ChecksumPolicy::Ignore),
+        Ok((seed.clone(), ChecksumStatus::Ignored))
+    );
+    assert_eq!(
+        status(data, ChecksumPolicy::Require),
+        Ok((seed.clone(), ChecksumStatus::Missing))
+    );
+
+    assert_eq!(
+        from_words(&damaged, Language::English),
+        Err(Error::InvalidChecksum)
+    );
+    let options = DecodeOptions::new().checksum_policy(ChecksumPolicy::Ignore);
+    assert_eq!(
+        from_words_with_options(&damaged, Language::English, &options),
+        Ok(seed)
+    );
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn single_word_groups() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        for &group in &[[0u8; 4], [0xff; 4], [0x12, 0x34, 0x56, 0x78]] {
+            let words = bytes_to_words_group(group, language).unwrap();
+            let phrase = to_words_no_checksum(&group, language).unwrap();
+            assert_eq!(words.join(" "), phrase);
+            assert_eq!(words_group_to_bytes(words, language), Ok(group));
+        }
+    }
+
+    assert_eq!(
+        words_group_to_bytes(["abbey", "xyzzy", "zoom"], Language::English),
+        Err(Error::UnknownWord {
+            index: 1,
+            word: "xyzzy".to_string(),
+            span: 0..5
+        })
+    );
+    // The largest value of 3 digits base 1626 is over u32::MAX.
+    let english = Language::English.wordlist().unwrap();
+    assert_eq!(
+        words_group_to_bytes(
+            [english[1625], english[1624], english[1623]],
+            Language::English
+        ),
+        Err(Error::InvalidWordGroup { index: 0 })
+    );
+}
+
+#[test]
+#[cfg(all(feature = "english", feature = "italian"))]
+fn is_valid_phrase() {
+    let phrase = to_words(&[9; 32], Language::Italian).unwrap();
+    assert!(is_valid(&phrase, Language::Italian));
+    assert_eq!(verify_checksum(&phrase, Language::Italian), Ok(true));
+
+    let (data, _) = phrase.rsplit_once(' ').unwrap();
+    assert!(is_valid(data, Language::Italian));
+    assert!(!is_valid(&phrase, Language::English));
+    assert!(!is_valid("", Language::Italian));
+}
+
+#[test]
+#[cfg(all(feature = "english", feature = "italian"))]
+fn too_few_words_for_checksum() {
+    let phrase = to_words(&[9; 32], Language::Italian).unwrap();
+    let words = phrase.split(' ').collect::<Vec<_>>();
+
+    for &count in &[1, 2, 3] {
+        assert_eq!(
+            verify_checksum(&words[..count].join(" "), Language::Italian),
+            Err(Error::TooFewWordsForChecksum {
+                found: count,
+                minimum: MIN_CHECKSUM_WORDS,
+            })
+        );
+    }
+    for &count in &[5, 6, 12, 24] {
+        assert_eq!(
+            verify_checksum(&words[..count].join(" "), Language::Italian),
+            Err(Error::InvalidWordCount { found: count })
+        );
+    }
+    assert_eq!(
+        verify_checksum("", Language::Italian),
+        Err(Error::EmptyPhrase)
+    );
+    assert!(!checksum_matches_word(
+        &words[..4],
+        words[4],
+        Language::Italian
+    ));
+    assert!(!checksum_matches_word(&[], words[0], Language::Italian));
+
+    // The all-zero 4 byte seed, every word is the first one and it's its
+    // own checksum.
+    assert_eq!(
+        to_words(&[0; 4], Language::English).unwrap(),
+        "abbey abbey abbey abbey"
+    );
+    assert_eq!(
+        verify_checksum("abbey abbey abbey abbey", Language::English),
+        Ok(true)
+    );
+
+    let mut wrong = words.clone();
+    wrong[24] = if words[24] == words[0] {
+        words[1]
+    } else {
+        words[0]
+    };
+    assert_eq!(
+        verify_checksum(&wrong.join(" "), Language::Italian),
+        Ok(false)
+    );
+}
+
+#[test]
+fn verify_checksum_every_seed_length() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        for len in (4..=MAX_SEED_LEN).step_by(4) {
+            let seed = (0..len as u8)
+                .map(|i| i.wrapping_mul(53))
+                .collect::<Vec<_>>();
+            let phrase = to_words(&seed, language).unwrap();
+            assert_eq!(verify_checksum(&phrase, language), Ok(true), "{}", len);
+
+            let words = phrase.split(' ').collect::<Vec<_>>();
+            let (checksum, data) = words.split_last().unwrap();
+            assert!(checksum_matches_word(data, checksum, language));
+            assert_eq!(from_words(&phrase, language), Ok(seed));
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "dutch")]
+fn checksum_at_position() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(71)).collect::<Vec<_>>();
+    let phrase = to_words(&seed, Language::Dutch).unwrap();
+    let mut words = phrase.split(' ').collect::<Vec<_>>();
+
+    assert_eq!(
+        from_words_checksum_at(&phrase, Language::Dutch, 24),
+        Ok(seed.clone())
+    );
+
+    let checksum = words.pop().unwrap();
+    words.insert(0, checksum);
+    let moved = words.join(" ");
+    assert_eq!(
+        from_words_checksum_at(&moved, Language::Dutch, 0),
+        Ok(seed.clone())
+    );
+    assert!(from_words(&moved, Language::Dutch).is_err());
+
+    assert_eq!(
+        from_words_checksum_at(&moved, Language::Dutch, 25),
+        Err(Error::InvalidWordCount { found: 25 })
+    );
+    let data = phrase.rsplit_once(' ').unwrap().0;
+    assert_eq!(
+        from_words_checksum_at(data, Language::Dutch, 0),
+        Err(Error::InvalidWordCount { found: 24 })
+    );
+}
+
+#[test]
+#[cfg(feature = "russian")]
+fn u128_round_trip() {
+    for &value in &[
+        0u128,
+        1,
+        u128::MAX,
+        0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+    ] {
+        let phrase = u128_to_words(value, Language::Russian).unwrap();
+        assert_eq!(phrase.split(' ').count(), 13);
+        assert_eq!(words_to_u128(&phrase, Language::Russian), Ok(value));
+        assert_eq!(
+            from_words(&phrase, Language::Russian),
+            Ok(value.to_le_bytes().to_vec())
+        );
+    }
+
+    let long = to_words(&[1; 32], Language::Russian).unwrap();
+    assert_eq!(
+        words_to_u128(&long, Language::Russian),
+        Err(Error::InvalidSeedLength { len: 32 })
+    );
+}
+
+#[test]
+#[cfg(feature = "portuguese")]
+fn batch_decode() {
+    let seeds = (0..64u8)
+        .map(|i| (0..32u8).map(|j| j ^ i.wrapping_mul(7)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mut phrases = seeds
+        .iter()
+        .map(|seed| to_words(seed, Language::Portuguese).unwrap())
+        .collect::<Vec<_>>();
+    phrases.push("xyzzy".to_string());
+    let phrases = phrases.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let expected = phrases
+        .iter()
+        .map(|phrase| from_words(phrase, Language::Portuguese))
+        .collect::<Vec<_>>();
+    assert_eq!(decode_batch(&phrases, Language::Portuguese), expected);
+    assert!(expected[..64]
+        .iter()
+        .zip(&seeds)
+        .all(|(r, s)| r.as_ref() == Ok(s)));
+    assert!(expected[64].is_err());
+
+    #[cfg(feature = "rayon")]
+    assert_eq!(
+        xmr_wordlist::decode_batch_par(&phrases, Language::Portuguese),
+        expected
+    );
+}
+
+#[test]
+fn unknown_word_spans() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(59)).collect::<Vec<_>>();
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        let phrase = to_words(&seed, language).unwrap();
+        let mut words = phrase.split(' ').collect::<Vec<_>>();
+        words[3] = "ｘｙｚｚｙ";
+        // Pasted with ideographic spaces, three bytes each.
+        let broken = format!("\u{3000}{}", words.join("\u{3000}"));
+
+        match from_words(&broken, language) {
+            Err(Error::UnknownWord { index, word, span }) => {
+                assert_eq!(index, 3);
+                assert_eq!(word, "ｘｙｚｚｙ");
+                assert_eq!(&broken[span.clone()], "ｘｙｚｚｙ");
+                let before = words[..3].iter().map(|w| w.len() + 3).sum::<usize>();
+                assert_eq!(span, 3 + before..3 + before + word.len());
+            }
+            other => panic!("{:?}: unexpected {:?}", language, other),
+        }
+    }
+}
+
+#[test]
+fn phrase_forms() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(61)).collect::<Vec<_>>();
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        let phrase = to_words(&seed, language).unwrap();
+        let trimmed = to_trimmed(&phrase, language);
+        assert_eq!(phrase_form(&phrase, language), PhraseForm::Full);
+
+        if language == Language::ChineseSimplified {
+            // Every word is a single character, already trimmed.
+            assert_eq!(phrase_form(&trimmed, language), PhraseForm::Full);
+            continue;
+        }
+
+        assert_ne!(phrase, trimmed, "{:?}", language);
+        let mut words = trimmed.split(' ').collect::<Vec<_>>();
+        let full = phrase.split(' ').collect::<Vec<_>>();
+        let long = (0..full.len())
+            .find(|&i| full[i] != words[i])
+            .expect("a word longer than the prefix");
+        assert_eq!(phrase_form(&trimmed, language), PhraseForm::Trimmed);
+
+        words[long] = full[long];
+        assert_eq!(phrase_form(&words.join(" "), language), PhraseForm::Mixed);
+    }
+
+    assert_eq!(phrase_form("", Language::English), PhraseForm::Full);
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn numbered_words() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(67)).collect::<Vec<_>>();
+    let phrase = to_words(&seed, Language::English).unwrap();
+    let words = phrase.split(' ').collect::<Vec<_>>();
+
+    let numbered = to_numbered_words(&seed, Language::English, 1).unwrap();
+    assert_eq!(numbered.len(), 25);
+    assert_eq!(numbered[0], format!("1. {}", words[0]));
+    assert_eq!(numbered[24], format!("25. {}", words[24]));
+
+    let numbered = to_numbered_words(&seed[..16], Language::English, 0).unwrap();
+    assert_eq!(numbered.len(), 13);
+    assert_eq!(numbered.last().unwrap().split(". ").next(), Some("12"));
+
+    assert_eq!(
+        to_numbered_words(&seed[..3], Language::English, 1),
+        Err(Error::InvalidSeedLength { len: 3 })
+    );
+}
+
+/// Reference encoding of a 32-bit value: its 3 digits base `radix`, each
+/// offset by the previous word index.
+#[cfg(feature = "english")]
+fn reference_group(val: u32, radix: u64) -> [u16; 3] {
+    let val = u64::from(val);
+    let digits = [val % radix, (val / radix) % radix, val / (radix * radix)];
+
+    let w1 = digits[0];
+    let w2 = (digits[1] + w1) % radix;
+    let w3 = (digits[2] + w2) % radix;
+    [w1 as u16, w2 as u16, w3 as u16]
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn group_boundaries() {
+    let len = Language::English.radix();
+    let values = [
+        0,
+        1,
+        len - 1,
+        len,
+        len + 1,
+        len * len - 1,
+        len * len,
+        u32::MAX / len,
+        u32::MAX - len,
+        u32::MAX - 1,
+        u32::MAX,
+    ];
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        for &val in &values {
+            let bytes = val.to_le_bytes();
+            let indices = to_indices(&bytes, language).unwrap();
+            assert_eq!(
+                indices[..3],
+                reference_group(val, u64::from(len)),
+                "{:?} {}",
+                language,
+                val
+            );
+
+            assert_eq!(from_indices(&indices[..3], language), Ok(bytes.to_vec()));
+            assert_eq!(
+                words_group_to_bytes(bytes_to_words_group(bytes, language).unwrap(), language),
+                Ok(bytes)
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(all(feature = "english", feature = "std"))]
+fn write_words_to_writer() {
+    use std::io::ErrorKind;
+
+    let seed = (0..32u8).map(|i| i.wrapping_mul(73)).collect::<Vec<_>>();
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        let mut out = Vec::new();
+        write_words(&seed, language, " ", &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            to_words(&seed, language).unwrap()
+        );
+    }
+
+    let mut out = Vec::new();
+    write_words(&seed[..16], Language::English, "\n", &mut out).unwrap();
+    let lines = String::from_utf8(out).unwrap();
+    assert_eq!(lines.lines().count(), 13);
+    assert_eq!(
+        lines.replace('\n', " "),
+        to_words(&seed[..16], Language::English).unwrap()
+    );
+
+    let mut out = Vec::new();
+    let error = write_words(&seed[..3], Language::English, " ", &mut out).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    assert_eq!(
+        error.get_ref().and_then(|e| e.downcast_ref::<Error>()),
+        Some(&Error::InvalidSeedLength { len: 3 })
+    );
+    assert!(out.is_empty());
+}
+
+#[test]
+#[cfg(feature = "german")]
+fn from_words_with_reports_each_word() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(79)).collect::<Vec<_>>();
+    let phrase = to_words(&seed, Language::German).unwrap();
+    let trimmed = to_trimmed(&phrase, Language::German);
+
+    let mut seen = Vec::new();
+    let bytes = from_words_with(&trimmed, Language::German, |i, word| {
+        seen.push((i, word.to_string()))
+    });
+    assert_eq!(bytes, Ok(seed.clone()));
+    assert_eq!(
+        seen,
+        phrase
+            .split(' ')
+            .enumerate()
+            .map(|(i, word)| (i, word.to_string()))
+            .collect::<Vec<_>>()
+    );
+
+    // Unknown words are skipped, the others are still reported before the
+    // error is returned.
+    let mut words = phrase.split(' ').collect::<Vec<_>>();
+    words[2] = "xyzzy";
+    let mut positions = Vec::new();
+    let result = from_words_with(&words.join(" "), Language::German, |i, _| positions.push(i));
+    assert!(matches!(result, Err(Error::UnknownWord { index: 2, .. })));
+    assert_eq!(positions, (0..25).filter(|&i| i != 2).collect::<Vec<_>>());
+
+    // Every word is resolved before the checksum is verified.
+    let mut words = phrase.split(' ').collect::<Vec<_>>();
+    words[24] = if words[0] == words[24] {
+        words[1]
+    } else {
+        words[0]
+    };
+    let mut count = 0;
+    let result = from_words_with(&words.join(" "), Language::German, |_, _| count += 1);
+    assert_eq!(result, Err(Error::InvalidChecksum));
+    assert_eq!(count, 25);
+}
+
+#[test]
+fn checksum_matches_trimmed_words() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(83)).collect::<Vec<_>>();
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        if language == Language::EnglishOld {
+            continue;
+        }
+
+        let phrase = to_words(&seed, language).unwrap();
+        let words = phrase.split(' ').collect::<Vec<_>>();
+        let (checksum, data) = words.split_last().unwrap();
+        assert!(checksum_matches_word(data, checksum, language));
+
+        let trimmed = to_trimmed(&phrase, language);
+        let trimmed = trimmed.split(' ').collect::<Vec<_>>();
+        let (trimmed_checksum, trimmed_data) = trimmed.split_last().unwrap();
+        assert!(checksum_matches_word(
+            trimmed_data,
+            trimmed_checksum,
+            language
+        ));
+        assert!(checksum_matches_word(trimmed_data, checksum, language));
+        assert!(checksum_matches_word(data, trimmed_checksum, language));
+    }
+}
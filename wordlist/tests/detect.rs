@@ -0,0 +1,238 @@
+// Some imports are only used by the tests of optional languages.
+#![cfg_attr(
+    not(all(
+        feature = "english",
+        feature = "english-old",
+        feature = "italian",
+        feature = "japanese",
+        feature = "spanish"
+    )),
+    allow(unused_imports)
+)]
+
+extern crate xmr_wordlist;
+
+use xmr_wordlist::{
+    analyze, classify_english, detect, detect_with_checksum, detection_collision_rate, from_words,
+    from_words_auto, plausible_languages, recover_language, same_entropy, to_words, translate,
+    DetectionResult, EnglishKind, Error, Language,
+};
+
+fn seed(n: u8) -> Vec<u8> {
+    (0..32u8)
+        .map(|i| i.wrapping_mul(n).wrapping_add(n))
+        .collect()
+}
+
+#[test]
+fn from_words_auto_detects_language() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        let phrase = to_words(&seed(17), language).unwrap();
+        assert_eq!(from_words_auto(&phrase), Ok((language, seed(17))));
+    }
+}
+
+#[test]
+#[cfg(all(feature = "english", feature = "italian"))]
+fn from_words_auto_language_tag() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        let phrase = to_words(&seed(19), language).unwrap();
+        let expected = Ok((language, seed(19)));
+
+        let tagged = format!("{}\n{}", language.english_name(), phrase);
+        assert_eq!(from_words_auto(&tagged), expected);
+        let tagged = format!("  LANG: {}\r\n{}", language.iso_code(), phrase);
+        assert_eq!(from_words_auto(&tagged), expected);
+        let tagged = format!("language:{} {}", language.iso_code(), phrase);
+        assert_eq!(from_words_auto(&tagged), expected);
+    }
+
+    // The tag overrides detection, even when another language matches.
+    let phrase = to_words(&seed(19), Language::English).unwrap();
+    assert!(matches!(
+        from_words_auto(&format!("lang: ja\n{}", phrase)),
+        Err(Error::UnknownWords(_))
+    ));
+    assert_eq!(
+        from_words_auto(&format!("lang: klingon\n{}", phrase)),
+        Err(Error::UnknownLanguage("klingon".to_string()))
+    );
+
+    // A first line that's a word isn't taken as a tag.
+    let italian = to_words(&seed(19), Language::Italian).unwrap();
+    assert!(from_words_auto(&format!("italiano\n{}", italian)).is_err());
+    assert_eq!(
+        from_words_auto(&format!("Italiano\n{}", italian)),
+        Ok((Language::Italian, seed(19)))
+    );
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn same_entropy_matching() {
+    let phrase = to_words(&seed(3), Language::English).unwrap();
+    assert_eq!(same_entropy(&phrase, &phrase), Ok(true));
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn same_entropy_not_matching() {
+    let a = to_words(&seed(3), Language::English).unwrap();
+    let b = to_words(&seed(5), Language::English).unwrap();
+    assert_eq!(same_entropy(&a, &b), Ok(false));
+}
+
+#[test]
+#[cfg(all(feature = "english", feature = "japanese"))]
+fn same_entropy_cross_language() {
+    let english = to_words(&seed(7), Language::English).unwrap();
+    let japanese = translate(&english, Language::English, Language::Japanese).unwrap();
+    assert_eq!(same_entropy(&english, &japanese), Ok(true));
+
+    let other = to_words(&seed(9), Language::Japanese).unwrap();
+    assert_eq!(same_entropy(&english, &other), Ok(false));
+}
+
+#[test]
+#[cfg(all(feature = "english", feature = "english-old"))]
+fn classify_english_phrases() {
+    let modern = to_words(&seed(13), Language::English).unwrap();
+    assert_eq!(classify_english(&modern), EnglishKind::Modern24);
+
+    let data = modern.rsplit_once(' ').unwrap().0;
+    assert_eq!(classify_english(data), EnglishKind::Modern24);
+
+    let old = to_words(&seed(13)[..16], Language::EnglishOld).unwrap();
+    assert_eq!(classify_english(&old), EnglishKind::Old13WithChecksum);
+
+    let old_data = old.rsplit_once(' ').unwrap().0;
+    assert_eq!(classify_english(old_data), EnglishKind::Old12);
+
+    let old_long = to_words(&seed(13), Language::EnglishOld).unwrap();
+    assert_eq!(classify_english(&old_long), EnglishKind::Unknown);
+    assert_eq!(classify_english(""), EnglishKind::Unknown);
+}
+
+#[test]
+#[cfg(all(feature = "english", feature = "spanish"))]
+fn detect_mixed_languages() {
+    let spanish = to_words(&seed(21), Language::Spanish).unwrap();
+    let mut words = spanish.split(' ').collect::<Vec<_>>();
+    words[7] = "zodiac";
+    let phrase = words.join(" ");
+
+    match detect(&phrase) {
+        Err(Error::MixedLanguages(mixed)) => {
+            assert_eq!(mixed.len(), words.len());
+            assert_eq!(mixed[7], (7, Language::English));
+            assert!(mixed
+                .iter()
+                .filter(|&&(i, _)| i != 7)
+                .all(|&(_, language)| language == Language::Spanish));
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+
+    assert_eq!(detect("zodiac xyzzy"), Err(Error::NoLanguageMatch));
+}
+
+#[test]
+fn recover_language_decodes_in_every_language() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        let phrase = to_words(&seed(29), language).unwrap();
+        let recovered = recover_language(&phrase);
+        assert!(recovered.contains(&(language, seed(29))), "{:?}", language);
+        for (other, bytes) in recovered {
+            assert_eq!(from_words(&phrase, other), Ok(bytes));
+        }
+    }
+
+    assert!(recover_language("zodiac xyzzy").is_empty());
+    assert!(recover_language("").is_empty());
+}
+
+#[test]
+fn collision_rates() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        assert_eq!(detection_collision_rate(language, language, 16), 1.0);
+    }
+
+    assert_eq!(
+        detection_collision_rate(Language::Japanese, Language::English, 64),
+        0.0
+    );
+    assert_eq!(
+        detection_collision_rate(Language::English, Language::Spanish, 0),
+        0.0
+    );
+
+    let rate = detection_collision_rate(Language::Spanish, Language::Portuguese, 64);
+    assert!((0.0..=1.0).contains(&rate));
+    assert_eq!(
+        detection_collision_rate(Language::Spanish, Language::Portuguese, 64),
+        rate
+    );
+}
+
+#[test]
+#[cfg(feature = "japanese")]
+fn analyze_phrases() {
+    let phrase = to_words(&seed(31), Language::Japanese).unwrap();
+    assert_eq!(
+        analyze(&phrase),
+        Ok(DetectionResult {
+            language: Language::Japanese,
+            checksum_valid: true,
+            word_count: 25,
+        })
+    );
+
+    let data = phrase.rsplit_once(' ').unwrap().0;
+    assert_eq!(
+        analyze(data),
+        Ok(DetectionResult {
+            language: Language::Japanese,
+            checksum_valid: false,
+            word_count: 24,
+        })
+    );
+
+    let words = phrase.split(' ').collect::<Vec<_>>();
+    let wrong = if words[24] == words[0] {
+        words[1]
+    } else {
+        words[0]
+    };
+    let damaged = format!("{} {}", data, wrong);
+    let result = analyze(&damaged).unwrap();
+    assert_eq!(result.language, Language::Japanese);
+    assert!(!result.checksum_valid);
+
+    assert_eq!(analyze(""), Err(Error::EmptyPhrase));
+}
+
+#[test]
+fn plausible_languages_by_script() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        for word in language.wordlist().unwrap() {
+            assert!(
+                plausible_languages(word).contains(&language),
+                "{:?}: {:?}",
+                language,
+                word
+            );
+        }
+
+        let phrase = to_words(&seed(23), language).unwrap();
+        let plausible = plausible_languages(&phrase);
+        assert!(plausible
+            .iter()
+            .all(|other| other.script() == language.script()));
+        assert_eq!(detect_with_checksum(&phrase), Ok(language));
+    }
+
+    assert_eq!(plausible_languages("абажур"), vec![Language::Russian]);
+    assert!(!plausible_languages("abbey").contains(&Language::Russian));
+    assert!(plausible_languages("abbey абажур").is_empty());
+    assert!(plausible_languages("abbey 🙂").is_empty());
+}
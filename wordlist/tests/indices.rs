@@ -0,0 +1,62 @@
+#![cfg(feature = "english")]
+
+extern crate xmr_wordlist;
+
+use xmr_wordlist::{from_indices, from_words, to_indices, to_words, Error, Language};
+
+fn seeds(len: usize) -> Vec<Vec<u8>> {
+    (0..64u8)
+        .map(|i| {
+            (0..len as u8)
+                .map(|j| j.wrapping_mul(37).wrapping_add(i.wrapping_mul(101)))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn indices_round_trip() {
+    for &len in &[4, 16, 32] {
+        for seed in seeds(len) {
+            let indices = to_indices(&seed, Language::English).unwrap();
+            assert_eq!(indices.len(), len / 4 * 3 + 1);
+            assert_eq!(from_indices(&indices, Language::English), Ok(seed.clone()));
+
+            // The checksum index is optional.
+            let data = &indices[..indices.len() - 1];
+            assert_eq!(from_indices(data, Language::English), Ok(seed.clone()));
+        }
+    }
+}
+
+#[test]
+fn words_round_trip() {
+    for &len in &[4, 16, 32] {
+        for seed in seeds(len) {
+            let phrase = to_words(&seed, Language::English).unwrap();
+            assert_eq!(from_words(&phrase, Language::English), Ok(seed));
+        }
+    }
+}
+
+#[test]
+fn invalid_lengths() {
+    for &len in &[0, 3, 5, 31] {
+        assert_eq!(
+            to_indices(&vec![0; len], Language::English),
+            Err(Error::InvalidSeedLength { len })
+        );
+    }
+
+    assert_eq!(
+        from_indices(&[0, 1], Language::English),
+        Err(Error::InvalidWordCount { found: 2 })
+    );
+    assert_eq!(
+        from_indices(&[0, 1, 1626], Language::English),
+        Err(Error::InvalidWordIndex {
+            index: 2,
+            value: 1626
+        })
+    );
+}
@@ -0,0 +1,559 @@
+extern crate xmr_wordlist;
+
+use xmr_wordlist::{from_words, to_words, Language};
+
+/// Deterministic battery of seeds, including the all-zero and all-0xFF ones.
+fn seeds() -> Vec<Vec<u8>> {
+    let mut seeds = vec![vec![0u8; 32], vec![0xffu8; 32]];
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+
+    for _ in 0..32 {
+        let mut seed = Vec::with_capacity(32);
+        for _ in 0..32 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            seed.push(state as u8);
+        }
+        seeds.push(seed);
+    }
+
+    seeds
+}
+
+fn trim(phrase: &str, prefix_len: usize) -> String {
+    phrase
+        .split(' ')
+        .map(|word| word.chars().take(prefix_len).collect::<String>())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[test]
+fn self_check() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        assert_eq!(language.self_check(), Ok(()), "{:?}", language);
+    }
+}
+
+#[test]
+fn trimmed_checksum_round_trip() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        for seed in seeds() {
+            let phrase = to_words(&seed, language).unwrap();
+            assert_eq!(from_words(&phrase, language).unwrap(), seed, "{}", phrase);
+
+            // Trimmed words are ambiguous for lists with shared prefixes.
+            if language == Language::EnglishOld {
+                continue;
+            }
+
+            let trimmed = trim(&phrase, language.unique_prefix_len());
+            assert_eq!(from_words(&trimmed, language).unwrap(), seed, "{}", trimmed);
+        }
+    }
+}
+
+#[test]
+fn from_locale() {
+    assert_eq!(Language::from_locale("ja-JP"), Some(Language::Japanese));
+    assert_eq!(
+        Language::from_locale("pt_BR.UTF-8"),
+        Some(Language::Portuguese)
+    );
+    assert_eq!(
+        Language::from_locale("zh-Hans-CN"),
+        Some(Language::ChineseSimplified)
+    );
+    assert_eq!(
+        Language::from_locale("zh-CN"),
+        Some(Language::ChineseSimplified)
+    );
+    assert_eq!(Language::from_locale("zh-Hant-TW"), None);
+    assert_eq!(Language::from_locale("zh-TW"), None);
+    assert_eq!(Language::from_locale("EN-us"), Some(Language::English));
+    assert_eq!(
+        Language::from_locale("en-x-old"),
+        Some(Language::EnglishOld)
+    );
+    assert_eq!(Language::from_locale("ko-KR"), None);
+    assert_eq!(Language::from_locale(""), None);
+}
+
+#[test]
+fn from_accept_language() {
+    assert_eq!(
+        Language::from_accept_language("ja,en-US;q=0.8"),
+        Some(Language::Japanese)
+    );
+    assert_eq!(
+        Language::from_accept_language("en-US;q=0.5, fr-CH, de;q=0.9"),
+        Some(Language::French)
+    );
+    assert_eq!(
+        Language::from_accept_language("ko-KR, zh-TW;q=0.9, pt-BR;q=0.8, *;q=0.1"),
+        Some(Language::Portuguese)
+    );
+    assert_eq!(
+        Language::from_accept_language("es;q=0.7, it;q=0.7"),
+        Some(Language::Spanish)
+    );
+    assert_eq!(
+        Language::from_accept_language("ru;q=0, nl;q=bad, eo;Q=0.2"),
+        Some(Language::Esperanto)
+    );
+    assert_eq!(Language::from_accept_language("ko, *"), None);
+    assert_eq!(Language::from_accept_language(""), None);
+}
+
+#[test]
+#[cfg(all(
+    feature = "chinese-simplified",
+    feature = "dutch",
+    feature = "english",
+    feature = "english-old",
+    feature = "esperanto",
+    feature = "french",
+    feature = "german",
+    feature = "italian",
+    feature = "japanese",
+    feature = "lojban",
+    feature = "portuguese",
+    feature = "russian",
+    feature = "spanish"
+))]
+fn wordlist_fingerprints() {
+    let expected = [
+        (Language::ChineseSimplified, 0x4fd4_4add_9916_7995),
+        (Language::Dutch, 0x5e63_f570_ef99_3581),
+        (Language::English, 0x488b_4148_0a0c_8b05),
+        (Language::EnglishOld, 0xc1c5_0526_a057_0139),
+        (Language::Esperanto, 0x3152_5049_ebd9_a324),
+        (Language::French, 0x1ff7_9dfc_be48_35e3),
+        (Language::German, 0x4288_4a24_b692_df26),
+        (Language::Italian, 0xe930_31a7_c54b_f0e1),
+        (Language::Japanese, 0xa048_66f3_da18_1fce),
+        (Language::Lojban, 0xa45e_901c_63dd_655c),
+        (Language::Portuguese, 0x3e60_0503_6ceb_3ada),
+        (Language::Russian, 0x4100_62e9_acde_e512),
+        (Language::Spanish, 0xadef_eeca_14ee_e3f7),
+    ];
+
+    assert_eq!(expected.len(), Language::all().len());
+    for &(language, fingerprint) in &expected {
+        assert_eq!(
+            language.wordlist_fingerprint(),
+            fingerprint,
+            "{:?}",
+            language
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "lojban")]
+fn language_available() {
+    assert!(Language::Lojban.is_available());
+    assert!(Language::Lojban.wordlist().is_ok());
+}
+
+#[test]
+#[cfg(not(feature = "lojban"))]
+fn language_not_compiled() {
+    use xmr_wordlist::{from_mixed, Error};
+
+    assert!(!Language::Lojban.is_available());
+    assert_eq!(
+        Language::Lojban.wordlist(),
+        Err(Error::LanguageNotCompiled(Language::Lojban))
+    );
+    assert_eq!(
+        to_words(&[0u8; 32], Language::Lojban),
+        Err(Error::LanguageNotCompiled(Language::Lojban))
+    );
+    assert_eq!(
+        from_mixed(&["12", "fanmo", "1625"], Language::Lojban),
+        Err(Error::LanguageNotCompiled(Language::Lojban))
+    );
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn words_with_prefix() {
+    assert_eq!(Language::English.words_with_prefix("abs"), vec!["absorb"]);
+    assert_eq!(
+        Language::English.words_with_prefix("zo"),
+        vec!["zodiac", "zombie", "zones", "zoom"]
+    );
+    assert!(Language::English.words_with_prefix("zzz").is_empty());
+    assert_eq!(Language::English.words_with_prefix("").len(), 1626);
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        let wordlist = language.wordlist().unwrap();
+        for word in wordlist.iter().step_by(97) {
+            let prefix = word.chars().take(2).collect::<String>();
+            let expected = wordlist
+                .iter()
+                .filter(|w| w.starts_with(prefix.as_str()))
+                .count();
+            let found = language.words_with_prefix(&prefix);
+            assert_eq!(found.len(), expected, "{:?} {}", language, prefix);
+            assert!(found.contains(word));
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn word_at_and_index() {
+    assert_eq!(Language::English.word_at(0), Some("abbey"));
+    assert_eq!(Language::English.word_at(1625), Some("zoom"));
+    assert_eq!(Language::English.word_at(1626), None);
+    assert_eq!(Language::English.word_at(u32::MAX), None);
+
+    assert_eq!(Language::English.word_index("zoom"), Some(1625));
+    assert_eq!(Language::English.word_index("abb"), Some(0));
+    assert_eq!(Language::English.word_index("xyzzy"), None);
+}
+
+#[test]
+#[cfg(all(
+    feature = "english",
+    feature = "japanese",
+    feature = "portuguese",
+    feature = "spanish"
+))]
+fn shared_and_unique_words() {
+    use xmr_wordlist::{shared_words, unique_to};
+
+    let shared = shared_words(Language::Spanish, Language::Portuguese);
+    let unique = unique_to(Language::Spanish, Language::Portuguese);
+    assert!(!shared.is_empty());
+    assert_eq!(shared.len() + unique.len(), 1626);
+    let mut reverse = shared_words(Language::Portuguese, Language::Spanish);
+    let mut sorted = shared.clone();
+    reverse.sort_unstable();
+    sorted.sort_unstable();
+    assert_eq!(sorted, reverse);
+
+    assert_eq!(
+        shared_words(Language::English, Language::English).len(),
+        1626
+    );
+    assert!(unique_to(Language::English, Language::English).is_empty());
+    assert!(shared_words(Language::English, Language::Japanese).is_empty());
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn encoded_capacity_upper_bound() {
+    use xmr_wordlist::encoded_capacity;
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        for &byte in &[0u8, 0x5a, 0xff] {
+            let phrase = to_words(&[byte; 32], language).unwrap();
+            assert!(phrase.len() <= encoded_capacity(32, language));
+        }
+    }
+
+    // 25 words of at most 12 bytes plus 24 spaces.
+    assert_eq!(encoded_capacity(32, Language::English), 25 * 12 + 24);
+    assert_eq!(encoded_capacity(0, Language::English), 0);
+}
+
+#[test]
+#[cfg(feature = "chinese-simplified")]
+fn word_len_stats() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        let wordlist = language.wordlist().unwrap();
+        let max_chars = wordlist.iter().map(|w| w.chars().count()).max().unwrap();
+        let max_bytes = wordlist.iter().map(|w| w.len()).max().unwrap();
+
+        assert_eq!(language.max_word_len(), max_chars, "{:?}", language);
+        assert_eq!(language.max_word_byte_len(), max_bytes, "{:?}", language);
+        assert!(language.average_word_len() <= max_chars as f64);
+        assert!(language.average_word_len() >= 1.0);
+    }
+
+    assert_eq!(Language::ChineseSimplified.max_word_len(), 1);
+    assert_eq!(Language::ChineseSimplified.max_word_byte_len(), 3);
+    assert_eq!(Language::ChineseSimplified.average_word_len(), 1.0);
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn words_iter() {
+    let mut words = Language::English.words_iter();
+    assert_eq!(words.len(), 1626);
+    assert_eq!(words.next(), Some("abbey"));
+    assert_eq!(words.next_back(), Some("zoom"));
+    assert_eq!(words.len(), 1624);
+    assert_eq!(words.nth(1), Language::English.word_at(2));
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        let wordlist = language.wordlist().unwrap();
+        assert!(language.words_iter().eq(wordlist.iter().cloned()));
+        assert!(language
+            .words_iter()
+            .rev()
+            .eq(wordlist.iter().rev().cloned()));
+    }
+}
+
+#[test]
+fn radix() {
+    use xmr_wordlist::WordlistSource;
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        assert_eq!(language.radix(), 1626);
+        assert_eq!(WordlistSource::radix(&language), 1626);
+    }
+}
+
+#[test]
+#[cfg(all(feature = "english", feature = "german", feature = "japanese"))]
+fn reference_card() {
+    let card = Language::English.reference_card().collect::<Vec<_>>();
+    assert_eq!(card.len(), 1626);
+    assert_eq!(card[0], (0, "abbey"));
+    assert_eq!(card[1625], (1625, "zoom"));
+
+    let mut trimmed = Language::German.reference_card_trimmed();
+    assert_eq!(trimmed.len(), 1626);
+    let (i, word, prefix) = trimmed.next().unwrap();
+    assert_eq!(i, 0);
+    assert_eq!(Some(word), Language::German.word_at(0));
+    assert_eq!(prefix, trim(word, 4));
+
+    for (i, word, prefix) in Language::Japanese.reference_card_trimmed() {
+        assert_eq!(Language::Japanese.word_index(prefix), Some(i));
+        assert!(word.starts_with(prefix));
+    }
+}
+
+#[test]
+#[cfg(all(feature = "english", feature = "spanish"))]
+fn wordlist_diff() {
+    let spanish = Language::Spanish.wordlist().unwrap();
+    assert!(Language::Spanish.wordlist_diff(spanish).is_empty());
+
+    let mut patched = spanish.to_vec();
+    patched[5] = "xyzzy";
+    patched.swap(10, 20);
+    let diff = Language::Spanish.wordlist_diff(&patched);
+    assert_eq!(diff.added, vec!["xyzzy"]);
+    assert_eq!(diff.removed, vec![spanish[5]]);
+    assert_eq!(
+        diff.moved,
+        vec![(spanish[10], 10, 20), (spanish[20], 20, 10)]
+    );
+
+    let diff = Language::English.wordlist_diff(&[]);
+    assert_eq!(diff.removed.len(), 1626);
+    assert!(diff.added.is_empty() && diff.moved.is_empty());
+}
+
+#[test]
+fn parse_language_names() {
+    use xmr_wordlist::Error;
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        assert_eq!(language.english_name().parse(), Ok(language));
+        assert_eq!(
+            Language::from_english_name(language.native_name()),
+            Some(language)
+        );
+        for alias in language.aliases() {
+            assert_eq!(Language::from_english_name(alias), Some(language));
+            assert_eq!(alias.to_lowercase(), *alias);
+        }
+    }
+
+    assert_eq!(
+        Language::from_english_name("  Simplified Chinese "),
+        Some(Language::ChineseSimplified)
+    );
+    assert_eq!(
+        Language::from_english_name("MANDARIN"),
+        Some(Language::ChineseSimplified)
+    );
+    assert_eq!("brazilian".parse::<Language>(), Ok(Language::Portuguese));
+    assert_eq!("Castilian".parse::<Language>(), Ok(Language::Spanish));
+    assert_eq!("español".parse::<Language>(), Ok(Language::Spanish));
+    assert_eq!(
+        "klingon".parse::<Language>(),
+        Err(Error::UnknownLanguage("klingon".to_string()))
+    );
+}
+
+#[test]
+fn monero_index() {
+    assert_eq!(Language::German.monero_index(), 0);
+    assert_eq!(Language::English.monero_index(), 1);
+    assert_eq!(Language::ChineseSimplified.monero_index(), 9);
+    assert_eq!(Language::EnglishOld.monero_index(), 12);
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        assert_eq!(
+            Language::from_monero_index(language.monero_index()),
+            Some(language)
+        );
+    }
+    assert_eq!(Language::from_monero_index(13), None);
+}
+
+#[test]
+#[cfg(all(
+    feature = "chinese-simplified",
+    feature = "english",
+    feature = "german"
+))]
+fn closest_word() {
+    assert_eq!(Language::English.closest_word("zoom", None), Some("zoom"));
+    assert_eq!(Language::English.closest_word("zoo", None), Some("zoom"));
+    assert_eq!(
+        Language::English.closest_word("zidiac", None),
+        Some("zodiac")
+    );
+    assert_eq!(Language::English.closest_word("xodiaq", None), None);
+    assert_eq!(
+        Language::English.closest_word("xodiaq", Some(2)),
+        Some("zodiac")
+    );
+    assert_eq!(Language::English.closest_word("qqqqqqqq", None), None);
+
+    // Without a limit the closest word is always found, unless tied.
+    assert_eq!(
+        Language::English.closest_word("xodiaq", Some(usize::MAX)),
+        Some("zodiac")
+    );
+    assert_eq!(
+        Language::English.closest_word("zoom", Some(usize::MAX)),
+        Some("zoom")
+    );
+
+    assert_eq!(Language::German.default_max_distance(), 2);
+    assert_eq!(Language::English.default_max_distance(), 1);
+
+    // A single edit of a Chinese word is another word, so there's no
+    // correction by default.
+    assert_eq!(Language::ChineseSimplified.default_max_distance(), 0);
+    let word = Language::ChineseSimplified.word_at(0).unwrap();
+    assert_eq!(
+        Language::ChineseSimplified.closest_word(word, None),
+        Some(word)
+    );
+    assert_eq!(Language::ChineseSimplified.closest_word("龘", None), None);
+}
+
+#[test]
+fn representations_round_trip() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        assert_eq!(Language::from_locale(language.iso_code()), Some(language));
+        assert_eq!(
+            Language::from_locale(&language.iso_code().to_uppercase()),
+            Some(language)
+        );
+        assert_eq!(language.english_name().parse(), Ok(language));
+        assert_eq!(language.native_name().parse(), Ok(language));
+        assert_eq!(language.english_name().to_uppercase().parse(), Ok(language));
+        for alias in language.aliases() {
+            assert_eq!(alias.parse(), Ok(language));
+        }
+        assert_eq!(
+            Language::from_monero_index(language.monero_index()),
+            Some(language)
+        );
+
+        let info = language.describe();
+        assert_eq!(info.english_name, language.english_name());
+        assert_eq!(info.native_name, language.native_name());
+        assert_eq!(info.iso_code, language.iso_code());
+        assert_eq!(info.unique_prefix_len, language.unique_prefix_len());
+        assert_eq!(info.script, language.script());
+        assert_eq!(info.input_hint, language.input_hint());
+    }
+
+    // No representation of one language may parse as another.
+    for (i, &a) in Language::all().iter().enumerate() {
+        for &b in &Language::all()[i + 1..] {
+            assert_ne!(a.iso_code(), b.iso_code());
+            assert_ne!(a.monero_index(), b.monero_index());
+            assert_ne!(a.english_name(), b.english_name());
+            assert_ne!(a.native_name(), b.native_name());
+            for alias in a.aliases() {
+                assert!(!b.aliases().contains(alias));
+            }
+        }
+    }
+}
+
+#[test]
+fn default_language() {
+    let selected = [
+        (
+            cfg!(feature = "default-lang-chinese-simplified"),
+            Language::ChineseSimplified,
+        ),
+        (cfg!(feature = "default-lang-dutch"), Language::Dutch),
+        (
+            cfg!(feature = "default-lang-english-old"),
+            Language::EnglishOld,
+        ),
+        (
+            cfg!(feature = "default-lang-esperanto"),
+            Language::Esperanto,
+        ),
+        (cfg!(feature = "default-lang-french"), Language::French),
+        (cfg!(feature = "default-lang-german"), Language::German),
+        (cfg!(feature = "default-lang-italian"), Language::Italian),
+        (cfg!(feature = "default-lang-japanese"), Language::Japanese),
+        (cfg!(feature = "default-lang-lojban"), Language::Lojban),
+        (
+            cfg!(feature = "default-lang-portuguese"),
+            Language::Portuguese,
+        ),
+        (cfg!(feature = "default-lang-russian"), Language::Russian),
+        (cfg!(feature = "default-lang-spanish"), Language::Spanish),
+    ];
+
+    let fallback = if Language::English.is_available() {
+        Language::English
+    } else {
+        Language::all()
+            .iter()
+            .cloned()
+            .find(|l| l.is_available())
+            .unwrap_or(Language::English)
+    };
+    let expected = selected
+        .iter()
+        .find(|&&(enabled, _)| enabled)
+        .map_or(fallback, |&(_, language)| language);
+
+    assert_eq!(Language::default(), expected);
+}
+
+#[test]
+fn text_layout() {
+    use xmr_wordlist::Direction;
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        assert_eq!(language.writing_direction(), Direction::Ltr);
+        assert_eq!(language.describe().writing_direction, Direction::Ltr);
+
+        let spaces = !matches!(language, Language::ChineseSimplified | Language::Japanese);
+        assert_eq!(language.uses_spaces(), spaces, "{:?}", language);
+        assert_eq!(language.describe().uses_spaces, spaces);
+    }
+}
+
+#[test]
+fn default_language_is_usable() {
+    let language = Language::default();
+    assert!(language.is_available());
+
+    let phrase = to_words(&[5; 32], language).unwrap();
+    assert_eq!(from_words(&phrase, language), Ok(vec![5; 32]));
+    assert_eq!("klingon".parse::<Language>().unwrap_or_default(), language);
+}
@@ -0,0 +1,93 @@
+// Some imports are only used by the tests of optional languages.
+#![cfg_attr(
+    not(all(
+        feature = "dutch",
+        feature = "english",
+        feature = "english-old",
+        feature = "german"
+    )),
+    allow(unused_imports)
+)]
+
+extern crate xmr_wordlist;
+
+use xmr_wordlist::{to_trimmed, to_words, Error, Language, Mnemonic, TOTAL_WORD_COUNT};
+
+#[test]
+#[cfg(feature = "english")]
+fn iterate_words() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(11)).collect::<Vec<_>>();
+    let mnemonic = Mnemonic::from_bytes(&seed, Language::English).unwrap();
+
+    let mut words = Vec::new();
+    for word in &mnemonic {
+        words.push(word);
+    }
+
+    assert_eq!(words.len(), TOTAL_WORD_COUNT);
+    assert_eq!(mnemonic.words().len(), TOTAL_WORD_COUNT);
+    assert_eq!(words.join(" "), to_words(&seed, Language::English).unwrap());
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn redacted_debug() {
+    let seed = [7u8; 32];
+    let mnemonic = Mnemonic::from_bytes(&seed, Language::English).unwrap();
+    let debug = format!("{:?}", mnemonic);
+
+    assert_eq!(debug, "Mnemonic { language: English, words: [REDACTED] }");
+    for word in &mnemonic {
+        assert!(!debug.contains(word));
+    }
+}
+
+#[test]
+#[cfg(feature = "english")]
+fn compare_and_reveal() {
+    let seed = [7u8; 32];
+    let mnemonic = Mnemonic::from_bytes(&seed, Language::English).unwrap();
+    let phrase = mnemonic.phrase();
+
+    assert_eq!(phrase, to_words(&seed, Language::English).unwrap());
+    assert_eq!(
+        Mnemonic::from_phrase(&phrase, Language::English).unwrap(),
+        mnemonic
+    );
+    assert_eq!(mnemonic.clone(), mnemonic);
+    assert_ne!(
+        Mnemonic::from_bytes(&[8u8; 32], Language::English).unwrap(),
+        mnemonic
+    );
+}
+
+#[test]
+#[cfg(all(feature = "dutch", feature = "english-old", feature = "german"))]
+fn parse_detecting_language() {
+    let seed = (0..32u8).map(|i| i.wrapping_mul(13)).collect::<Vec<_>>();
+
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        let phrase = to_words(&seed, language).unwrap();
+        let mnemonic: Mnemonic = phrase.parse().unwrap();
+        assert_eq!(mnemonic.language(), language);
+        assert_eq!(mnemonic.as_bytes(), seed.as_slice());
+        assert_eq!(mnemonic.phrase(), phrase);
+    }
+
+    let phrase = to_words(&seed, Language::German).unwrap();
+    let trimmed = to_trimmed(&phrase, Language::German);
+    assert_eq!(trimmed.parse::<Mnemonic>().unwrap().phrase(), phrase);
+
+    assert_eq!(
+        "xyzzy plugh".parse::<Mnemonic>().unwrap_err(),
+        Error::NoLanguageMatch
+    );
+    assert_eq!("".parse::<Mnemonic>().unwrap_err(), Error::EmptyPhrase);
+
+    // Both checksums match, see the detect snapshot.
+    let phrase = to_words(&[0xff; 32], Language::Dutch).unwrap();
+    assert_eq!(
+        phrase.parse::<Mnemonic>().unwrap_err(),
+        Error::AmbiguousLanguage(vec![Language::Dutch, Language::EnglishOld])
+    );
+}
@@ -0,0 +1,53 @@
+#![cfg(all(feature = "english", feature = "secrecy"))]
+
+extern crate secrecy;
+extern crate xmr_wordlist;
+
+use secrecy::{ExposeSecret, Secret};
+
+use xmr_wordlist::{from_secret_phrase, to_secret_words, to_words, Error, Language};
+
+#[test]
+fn secret_round_trip() {
+    let seed = Secret::new(vec![42u8; 32]);
+    let phrase = to_secret_words(&seed, Language::English).unwrap();
+    let decoded = from_secret_phrase(&phrase, Language::English).unwrap();
+
+    assert_eq!(decoded.expose_secret(), seed.expose_secret());
+}
+
+#[test]
+fn secret_phrase_errors_are_redacted() {
+    let phrase = to_words(&[7u8; 32], Language::English).unwrap();
+    let mut words = phrase.split(' ').collect::<Vec<_>>();
+
+    words[3] = "xyzzy";
+    let phrase = words.join(" ");
+    let error = from_secret_phrase(&Secret::new(phrase.clone()), Language::English).err();
+    match error {
+        Some(Error::UnknownWord {
+            index,
+            ref word,
+            ref span,
+        }) => {
+            assert_eq!(index, 3);
+            assert_eq!(word, "[REDACTED]");
+            assert_eq!(&phrase[span.clone()], "xyzzy");
+        }
+        ref other => panic!("{:?}", other),
+    }
+    assert!(!error.unwrap().to_string().contains("xyzzy"));
+
+    words[5] = "qwerty";
+    let error = from_secret_phrase(&Secret::new(words.join(" ")), Language::English).err();
+    match error {
+        Some(Error::UnknownWords(unknown)) => {
+            let found = unknown
+                .iter()
+                .map(|&(index, ref word, _)| (index, word.as_str()))
+                .collect::<Vec<_>>();
+            assert_eq!(found, [(3, "[REDACTED]"), (5, "[REDACTED]")]);
+        }
+        other => panic!("{:?}", other),
+    }
+}
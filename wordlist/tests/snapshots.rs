@@ -0,0 +1,182 @@
+//! Snapshots of the externally visible output, compared with the files in
+//! `tests/snapshots`. Run with `UPDATE_SNAPSHOTS=1` to write them again
+//! after an intended change, and review the diff.
+
+// The snapshots cover every language.
+#![cfg(all(
+    feature = "chinese-simplified",
+    feature = "dutch",
+    feature = "english",
+    feature = "english-old",
+    feature = "esperanto",
+    feature = "french",
+    feature = "german",
+    feature = "italian",
+    feature = "japanese",
+    feature = "lojban",
+    feature = "portuguese",
+    feature = "russian",
+    feature = "spanish",
+))]
+
+extern crate xmr_wordlist;
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+use xmr_wordlist::{detect, detect_with_checksum, to_trimmed, to_words, Error, Language};
+
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{}.snap", name));
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("missing snapshot {}, run with UPDATE_SNAPSHOTS=1", name));
+    if expected == actual {
+        return;
+    }
+
+    // Report the first line that differs, a missing line is shown as
+    // `<end>`.
+    let (expected, actual) = (
+        expected.lines().collect::<Vec<_>>(),
+        actual.lines().collect::<Vec<_>>(),
+    );
+    let line = expected
+        .iter()
+        .zip(&actual)
+        .position(|(expected, actual)| expected != actual)
+        .unwrap_or_else(|| expected.len().min(actual.len()));
+    panic!(
+        "snapshot {} changed at line {}:\n- {}\n+ {}\nrun with UPDATE_SNAPSHOTS=1 and review the diff",
+        name,
+        line + 1,
+        expected.get(line).unwrap_or(&"<end>"),
+        actual.get(line).unwrap_or(&"<end>"),
+    );
+}
+
+fn seeds() -> Vec<(&'static str, Vec<u8>)> {
+    vec![
+        ("zero", vec![0; 32]),
+        ("ones", vec![0xff; 32]),
+        ("counting", (0..32).collect()),
+        ("short", (0..16).map(|i| i * 17).collect()),
+    ]
+}
+
+#[test]
+fn to_words_snapshot() {
+    let mut out = String::new();
+    for &language in Language::all() {
+        for (name, seed) in seeds() {
+            let phrase = to_words(&seed, language).unwrap();
+            writeln!(out, "{:?} {}: {}", language, name, phrase).unwrap();
+        }
+    }
+
+    assert_snapshot("to_words", &out);
+}
+
+#[test]
+fn language_snapshot() {
+    let mut out = String::new();
+    for &language in Language::all() {
+        writeln!(out, "{:?}", language).unwrap();
+        writeln!(out, "{:?}", language.describe()).unwrap();
+    }
+
+    assert_snapshot("language", &out);
+}
+
+#[test]
+fn error_snapshot() {
+    let errors = vec![
+        Error::InvalidSeedLength { len: 3 },
+        Error::SeedTooLong { len: 36, max: 32 },
+        Error::SeedTooShort { len: 20, min: 32 },
+        Error::EmptyPhrase,
+        Error::InvalidHexLength { len: 5 },
+        Error::InvalidHexCharacter {
+            index: 2,
+            character: 'g',
+        },
+        Error::InvalidWordCount { found: 26 },
+        Error::TooFewWordsForChecksum {
+            found: 2,
+            minimum: 4,
+        },
+        Error::InvalidWordIndex {
+            index: 4,
+            value: 1626,
+        },
+        Error::UnknownWord {
+            index: 1,
+            word: "xyzzy".to_string(),
+            span: 4..9,
+        },
+        Error::UnknownWords(vec![
+            (1, "xyzzy".to_string(), 4..9),
+            (7, "plugh".to_string(), 40..45),
+        ]),
+        Error::AmbiguousWord {
+            index: 0,
+            word: "lov".to_string(),
+            span: 0..3,
+        },
+        Error::InvalidWordGroup { index: 3 },
+        Error::InvalidChecksum,
+        Error::NoLanguageMatch,
+        Error::AmbiguousLanguage(vec![Language::English, Language::EnglishOld]),
+        Error::MixedLanguages(vec![(0, Language::Spanish), (1, Language::English)]),
+        Error::InvalidWordlistLength { len: 1625 },
+        Error::InvalidPrefixLength { len: 0 },
+        Error::InvalidWordlistWord { index: 9 },
+        Error::PrefixCollision {
+            first: 0,
+            second: 1,
+        },
+        Error::UnknownLanguage("klingon".to_string()),
+        Error::LanguageNotCompiled(Language::Lojban),
+    ];
+
+    let mut out = String::new();
+    for error in errors {
+        writeln!(out, "{:?}", error).unwrap();
+        writeln!(out, "  {}", error).unwrap();
+    }
+
+    assert_snapshot("errors", &out);
+}
+
+#[test]
+fn detect_snapshot() {
+    let mut out = String::new();
+    for &language in Language::all() {
+        for (name, seed) in seeds() {
+            let phrase = to_words(&seed, language).unwrap();
+            let trimmed = to_trimmed(&phrase, language);
+            writeln!(
+                out,
+                "{:?} {}: {:?} / {:?} / trimmed {:?}",
+                language,
+                name,
+                detect(&phrase),
+                detect_with_checksum(&phrase),
+                detect_with_checksum(&trimmed)
+            )
+            .unwrap();
+        }
+    }
+
+    assert_snapshot("detect", &out);
+}
@@ -0,0 +1,47 @@
+extern crate xmr_wordlist;
+
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+use xmr_wordlist::{from_words, to_words, Language};
+
+#[test]
+fn concurrent_first_use() {
+    const THREADS: usize = 32;
+
+    // Encode without touching the lookup indexes, so the threads race to
+    // build them.
+    let languages = Language::all()
+        .iter()
+        .cloned()
+        .filter(|l| l.is_available())
+        .collect::<Vec<_>>();
+    let phrases = (0..THREADS)
+        .map(|i| {
+            let language = languages[i % languages.len()];
+            let seed = (0..32u8)
+                .map(|j| j.wrapping_mul(i as u8 + 1))
+                .collect::<Vec<_>>();
+            (language, seed.clone(), to_words(&seed, language).unwrap())
+        })
+        .collect::<Vec<_>>();
+
+    let barrier = Arc::new(Barrier::new(THREADS));
+    let handles = phrases
+        .into_iter()
+        .map(|(language, seed, phrase)| {
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                assert_eq!(from_words(&phrase, language), Ok(seed));
+                for word in phrase.split(' ') {
+                    assert!(language.word_index(word).is_some());
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
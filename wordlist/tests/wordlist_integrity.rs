@@ -0,0 +1,66 @@
+//! Integrity of the word list data, checked independently from
+//! `Language::self_check`. A mis-sized or corrupted list silently produces
+//! wrong seeds, so every invariant of the encoding is asserted here. The
+//! words are `&str`, so they're valid UTF-8 by construction.
+
+extern crate xmr_wordlist;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use xmr_wordlist::Language;
+
+/// The radix of the monero seed encoding.
+const WORDLIST_LENGTH: usize = 1626;
+
+fn prefix(word: &str, len: usize) -> String {
+    word.chars().take(len).collect()
+}
+
+#[test]
+fn wordlist_integrity() {
+    for &language in Language::all().iter().filter(|l| l.is_available()) {
+        let wordlist = language.wordlist().unwrap();
+        assert_eq!(wordlist.len(), WORDLIST_LENGTH, "{:?}", language);
+
+        let mut words = HashSet::with_capacity(wordlist.len());
+        let mut prefixes = HashMap::with_capacity(wordlist.len());
+        for (i, word) in wordlist.iter().enumerate() {
+            assert!(!word.is_empty(), "{:?}: word {} is empty", language, i);
+            assert_eq!(
+                word.trim(),
+                *word,
+                "{:?}: word {} has surrounding whitespace",
+                language,
+                i
+            );
+            assert!(
+                !word.chars().any(|c| c.is_whitespace() || c.is_control()),
+                "{:?}: word {} has whitespace or control characters",
+                language,
+                i
+            );
+            assert!(
+                words.insert(*word),
+                "{:?}: word {} {:?} is duplicated",
+                language,
+                i,
+                word
+            );
+
+            // The reference implementation keeps the old English list with
+            // words sharing a prefix, exact matches tell them apart.
+            if language == Language::EnglishOld {
+                continue;
+            }
+
+            let prefix = prefix(word, language.unique_prefix_len());
+            if let Some(first) = prefixes.insert(prefix.clone(), i) {
+                panic!(
+                    "{:?}: words {} and {} share the prefix {:?}",
+                    language, first, i, prefix
+                );
+            }
+        }
+    }
+}